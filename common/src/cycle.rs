@@ -0,0 +1,205 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Repetition in a sequence of states; from step `start` on, every `length` steps are equal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Step before the end of the first cycle which has the same state as step `n`
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+/// Floyd's tortoise and hare; keeps only two states in memory, but runs the step function about
+/// three times as often as needed
+pub fn floyd<S, F>(initial: S, mut step: F) -> Cycle
+where
+    S: PartialEq + Clone,
+    F: FnMut(&S) -> S,
+{
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let halfway = step(&hare);
+        hare = step(&halfway);
+    }
+
+    let mut start = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+    Cycle { start, length }
+}
+
+/// Brent's algorithm; keeps only two states in memory, with fewer steps than Floyd's
+pub fn brent<S, F>(initial: S, mut step: F) -> Cycle
+where
+    S: PartialEq + Clone,
+    F: FnMut(&S) -> S,
+{
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    tortoise = initial.clone();
+    hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+/// The states of a sequence up to its first repetition
+#[derive(Debug, Clone)]
+pub struct CycleStates<S> {
+    pub cycle: Cycle,
+    /// All states from the initial state until the end of the first cycle
+    pub states: Vec<S>,
+}
+
+impl<S> CycleStates<S> {
+    pub fn state_at(&self, n: usize) -> &S {
+        &self.states[self.cycle.equivalent_step(n)]
+    }
+}
+
+/// Remembers every state, so the step function runs exactly once per distinct state
+pub fn find_hashed<S, F>(initial: S, step: F) -> CycleStates<S>
+where
+    S: Hash + Eq + Clone,
+    F: FnMut(&S) -> S,
+{
+    match run_hashed(initial, step, usize::MAX) {
+        Ok(states) => states,
+        Err(_) => unreachable!("Sequence ended before it repeated"),
+    }
+}
+
+/// Like `find_hashed`, but gives up if the sequence has not repeated after `limit` steps
+pub fn find_hashed_within<S, F>(initial: S, step: F, limit: usize) -> Option<CycleStates<S>>
+where
+    S: Hash + Eq + Clone,
+    F: FnMut(&S) -> S,
+{
+    run_hashed(initial, step, limit).ok()
+}
+
+/// State after `n` steps, skipping ahead as soon as the sequence repeats
+pub fn state_at<S, F>(initial: S, step: F, n: usize) -> S
+where
+    S: Hash + Eq + Clone,
+    F: FnMut(&S) -> S,
+{
+    match run_hashed(initial, step, n) {
+        Ok(states) => states.state_at(n).clone(),
+        Err(state) => state,
+    }
+}
+
+/// Runs until the sequence repeats, or returns the state at step `limit` if that comes first
+fn run_hashed<S, F>(initial: S, mut step: F, limit: usize) -> Result<CycleStates<S>, S>
+where
+    S: Hash + Eq + Clone,
+    F: FnMut(&S) -> S,
+{
+    let mut seen = HashMap::from([(initial.clone(), 0)]);
+    let mut states = vec![initial];
+    loop {
+        if states.len() > limit {
+            return Err(states.swap_remove(limit));
+        }
+        let next = step(states.last().unwrap());
+        if let Some(&start) = seen.get(&next) {
+            let length = states.len() - start;
+            return Ok(CycleStates {
+                cycle: Cycle { start, length },
+                states,
+            });
+        }
+        seen.insert(next.clone(), states.len());
+        states.push(next);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0, 1, 2, 3, 4, 5, 6, 3, 4, ...
+    fn step(n: &u32) -> u32 {
+        if *n == 6 {
+            3
+        } else {
+            n + 1
+        }
+    }
+
+    #[test]
+    fn detects_cycle() {
+        let expected = Cycle {
+            start: 3,
+            length: 4,
+        };
+        assert_eq!(floyd(0, step), expected);
+        assert_eq!(brent(0, step), expected);
+        assert_eq!(find_hashed(0, step).cycle, expected);
+        assert_eq!(brent(3, step).start, 0);
+        assert_eq!(
+            floyd(5, |_| 5),
+            Cycle {
+                start: 0,
+                length: 1
+            }
+        );
+    }
+
+    #[test]
+    fn value_at_step() {
+        assert_eq!(state_at(0, step, 2), 2);
+        assert_eq!(state_at(0, step, 7), 3);
+        assert_eq!(
+            state_at(0, step, 1_000_000_000),
+            3 + (1_000_000_000 - 3) % 4
+        );
+        let states = find_hashed(0, step);
+        assert_eq!(states.states, [0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(*states.state_at(9), 5);
+        assert!(find_hashed_within(0, step, 6).is_none());
+        assert_eq!(find_hashed_within(0, step, 7).unwrap().cycle.length, 4);
+    }
+}
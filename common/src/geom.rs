@@ -0,0 +1,420 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{Add, Div, Mul, Rem, Sub},
+    str::FromStr,
+};
+
+/// Integer types which can be used as coordinate of a point
+pub trait Integer:
+    Copy
+    + Ord
+    + Debug
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    /// Absolute difference; never underflows, also not for unsigned types
+    fn abs_diff(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+            fn abs_diff(self, rhs: Self) -> Self {
+                self.max(rhs) - self.min(rhs)
+            }
+        })*
+    };
+}
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// The four horizontal and vertical directions, with `Up` towards lower `y`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    /// All directions, clockwise starting at `Up`
+    pub const ALL: [Direction4; 4] = [
+        Direction4::Up,
+        Direction4::Right,
+        Direction4::Down,
+        Direction4::Left,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction4::Left | Direction4::Right)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        !self.is_horizontal()
+    }
+
+    /// Offset of a single step as `(dx, dy)`
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Direction4::Up => (0, -1),
+            Direction4::Right => (1, 0),
+            Direction4::Down => (0, 1),
+            Direction4::Left => (-1, 0),
+        }
+    }
+}
+
+/// Horizontal, vertical and diagonal directions, with `Up` towards lower `y`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// All directions, clockwise starting at `Up`
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// Turns 45 degrees clockwise
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns 45 degrees counter clockwise
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// Offset of a single step as `(dx, dy)`
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Direction8::Up => (0, -1),
+            Direction8::UpRight => (1, -1),
+            Direction8::Right => (1, 0),
+            Direction8::DownRight => (1, 1),
+            Direction8::Down => (0, 1),
+            Direction8::DownLeft => (-1, 1),
+            Direction8::Left => (-1, 0),
+            Direction8::UpLeft => (-1, -1),
+        }
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        Self::ALL[direction as usize * 2]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Integer> Point2<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Moves `n` steps, or `None` when the coordinate type would over- or underflow
+    pub fn step_n<D: Into<Direction8>>(self, direction: D, n: T) -> Option<Self> {
+        let move_axis = |value: T, delta: isize| match delta {
+            -1 => value.checked_sub(n),
+            1 => value.checked_add(n),
+            _ => Some(value),
+        };
+        let (dx, dy) = direction.into().delta();
+        Some(Self::new(move_axis(self.x, dx)?, move_axis(self.y, dy)?))
+    }
+
+    pub fn step<D: Into<Direction8>>(self, direction: D) -> Option<Self> {
+        self.step_n(direction, T::ONE)
+    }
+
+    /// Moves a single step, if the result stays within `0..size.x` and `0..size.y`
+    pub fn step_within<D: Into<Direction8>>(self, direction: D, size: Self) -> Option<Self> {
+        self.step(direction).filter(|p| p.within(size))
+    }
+
+    /// Whether the point lies within `0..size.x` and `0..size.y`
+    pub fn within(self, size: Self) -> bool {
+        (T::ZERO..size.x).contains(&self.x) && (T::ZERO..size.y).contains(&self.y)
+    }
+
+    /// Horizontal and vertical neighbors that can be represented by the coordinate type
+    pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+        Direction4::ALL
+            .into_iter()
+            .filter_map(move |d| self.step(d))
+    }
+
+    /// All surrounding neighbors that can be represented by the coordinate type
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |d| self.step(d))
+    }
+}
+
+impl<T: Integer> Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Integer> Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Integer> Mul<T> for Point2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(point: Point2<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl<T: FromStr> FromStr for Point2<T>
+where
+    T::Err: Display,
+{
+    type Err = String;
+
+    /// Parses `x,y`; whitespace around the values is ignored
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y] = parse_components(s)?;
+        Ok(Self { x, y })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Integer> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    /// The point projected on the x/y plane
+    pub fn xy(self) -> Point2<T> {
+        Point2::new(self.x, self.y)
+    }
+
+    /// Dot product, treating both points as vectors
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Cross product, treating both points as vectors
+    pub fn cross(self, other: Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+}
+
+impl<T: Integer> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Integer> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Integer> Mul<T> for Point3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Display> Display for Point3<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+impl<T: FromStr> FromStr for Point3<T>
+where
+    T::Err: Display,
+{
+    type Err = String;
+
+    /// Parses `x,y,z`; whitespace around the values is ignored
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, z] = parse_components(s)?;
+        Ok(Self { x, y, z })
+    }
+}
+
+fn parse_components<T: FromStr, const N: usize>(s: &str) -> Result<[T; N], String>
+where
+    T::Err: Display,
+{
+    let values = s
+        .split(',')
+        .map(|v| v.trim().parse::<T>().map_err(|e| format!("{}: {}", e, s)))
+        .collect::<Result<Vec<_>, _>>()?;
+    values
+        .try_into()
+        .map_err(|_| format!("Expected {} components: {}", N, s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction4, Direction8, Point2, Point3};
+
+    #[test]
+    fn turns() {
+        assert_eq!(Direction4::Up.turn_right(), Direction4::Right);
+        assert_eq!(Direction4::Up.turn_left(), Direction4::Left);
+        assert_eq!(Direction4::Left.reverse(), Direction4::Right);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownLeft.reverse(), Direction8::UpRight);
+        assert_eq!(Direction8::from(Direction4::Down), Direction8::Down);
+    }
+
+    #[test]
+    fn steps() {
+        let origin = Point2::new(0_usize, 0);
+        assert_eq!(origin.step(Direction4::Up), None);
+        assert_eq!(origin.step(Direction8::DownRight), Some(Point2::new(1, 1)));
+        assert_eq!(origin.step_n(Direction4::Right, 5), Some(Point2::new(5, 0)));
+        let size = Point2::new(2, 2);
+        assert_eq!(
+            origin.step_within(Direction4::Right, size),
+            Some(Point2::new(1, 0))
+        );
+        assert_eq!(Point2::new(1, 0).step_within(Direction4::Right, size), None);
+        assert_eq!(origin.neighbors4().count(), 2);
+        assert_eq!(Point2::new(1_i8, 1).neighbors8().count(), 8);
+    }
+
+    #[test]
+    fn manhattan() {
+        assert_eq!(Point2::new(1_usize, 5).manhattan(Point2::new(4, 2)), 6);
+        assert_eq!(
+            Point3::new(-1_i64, 2, 3).manhattan(Point3::new(1, -2, 0)),
+            9
+        );
+    }
+
+    #[test]
+    fn products() {
+        let (a, b) = (Point3::new(1_i64, 0, 0), Point3::new(0, 1, 0));
+        assert_eq!(a.cross(b), Point3::new(0, 0, 1));
+        assert_eq!(b.cross(a), Point3::new(0, 0, -1));
+        assert_eq!(Point3::new(1, 2, 3).dot(Point3::new(4, -5, 6)), 12);
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("1,0,1".parse(), Ok(Point3::new(1_usize, 0, 1)));
+        assert_eq!(" -3, 4".parse(), Ok(Point2::new(-3_i32, 4)));
+        assert!("1,2".parse::<Point3<usize>>().is_err());
+        assert_eq!(Point3::new(1, 2, 3).to_string(), "1,2,3");
+    }
+}
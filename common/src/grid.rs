@@ -0,0 +1,411 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::geom::{Direction4, Direction8};
+
+/// Position in a grid as `(x, y)`, with the origin in the top left corner
+pub type GridPos = (usize, usize);
+
+/// Failure to parse a grid from text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum GridParseError {
+    /// The mapper rejected the character at `pos`
+    Cell { pos: GridPos, message: String },
+    /// Line `y` has a different width than the first line
+    Width {
+        y: usize,
+        width: usize,
+        expected: usize,
+    },
+}
+
+impl Display for GridParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Cell { pos, message } => write!(f, "{} at {},{}", message, pos.0, pos.1),
+            Self::Width { y, width, expected } => {
+                write!(f, "Line {} has width {}, expected {}", y, width, expected)
+            }
+        }
+    }
+}
+
+/// Two dimensional grid, stored row by row in a single `Vec`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from cells in row order; the cell count has to be a multiple of the width
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Result<Self, String> {
+        if width == 0 {
+            return if cells.is_empty() {
+                Ok(Self {
+                    width: 0,
+                    height: 0,
+                    cells,
+                })
+            } else {
+                Err(String::from("Grid width cannot be 0"))
+            };
+        }
+        if !cells.len().is_multiple_of(width) {
+            return Err(format!(
+                "{} cells do not fit in rows of {}",
+                cells.len(),
+                width
+            ));
+        }
+        Ok(Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    /// Parses a block of text, mapping every character to a cell
+    pub fn parse<F>(input: &str, mut mapper: F) -> Result<Self, String>
+    where
+        F: FnMut(char) -> T,
+    {
+        Self::try_parse(input, |c| Ok::<T, String>(mapper(c)))
+    }
+
+    /// Parses a block of text, mapping every character to a cell with a mapper that can fail
+    pub fn try_parse<F, E>(input: &str, mapper: F) -> Result<Self, String>
+    where
+        F: FnMut(char) -> Result<T, E>,
+        E: Display,
+    {
+        Self::parse_cells(input, mapper).map_err(|e| e.to_string())
+    }
+
+    /// Parses a block of text like `try_parse`, keeping where parsing failed
+    pub(crate) fn parse_cells<F, E>(input: &str, mut mapper: F) -> Result<Self, GridParseError>
+    where
+        F: FnMut(char) -> Result<T, E>,
+        E: Display,
+    {
+        let mut width = None;
+        let mut cells = vec![];
+        for (y, line) in input.trim_end().lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            let before = cells.len();
+            for (x, c) in line.chars().enumerate() {
+                cells.push(mapper(c).map_err(|e| GridParseError::Cell {
+                    pos: (x, y),
+                    message: e.to_string(),
+                })?);
+            }
+            let line_width = cells.len() - before;
+            match width {
+                None => width = Some(line_width),
+                Some(expected) if expected != line_width => {
+                    return Err(GridParseError::Width {
+                        y,
+                        width: line_width,
+                        expected,
+                    })
+                }
+                _ => {}
+            }
+        }
+        Ok(Self::from_vec(width.unwrap_or(0), cells)
+            .expect("Lines of the same width fill whole rows"))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// All cells in row order
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn in_bounds(&self, (x, y): GridPos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: GridPos) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: GridPos) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Moves from a position by a delta, if the result is still within the grid
+    pub fn offset(&self, (x, y): GridPos, (dx, dy): (isize, isize)) -> Option<GridPos> {
+        let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.in_bounds(pos).then_some(pos)
+    }
+
+    /// Moves a single step in a direction, if the result is still within the grid
+    pub fn step<D: Into<Direction8>>(&self, pos: GridPos, direction: D) -> Option<GridPos> {
+        self.offset(pos, direction.into().delta())
+    }
+
+    /// Horizontal and vertical neighbors within the grid, clockwise starting at the top
+    pub fn neighbors4(&self, pos: GridPos) -> impl Iterator<Item = GridPos> + '_ {
+        Direction4::ALL
+            .into_iter()
+            .filter_map(move |d| self.step(pos, d))
+    }
+
+    /// Horizontal, vertical and diagonal neighbors within the grid, clockwise starting at the top
+    pub fn neighbors8(&self, pos: GridPos) -> impl Iterator<Item = GridPos> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |d| self.step(pos, d))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` does not accept 0, an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Cells of column `x` from top to bottom; panics when `x` is out of bounds, like `row`
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "Column {} is out of bounds for width {}",
+            x,
+            self.width
+        );
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// All positions with their cell, in row order
+    pub fn iter(&self) -> impl Iterator<Item = (GridPos, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| ((i % self.width, i / self.width), cell))
+    }
+
+    /// Positions of all cells matching the predicate, in row order
+    pub fn positions<'a, P>(&'a self, mut predicate: P) -> impl Iterator<Item = GridPos> + 'a
+    where
+        P: FnMut(&T) -> bool + 'a,
+    {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// Position of the first cell matching the predicate, in row order
+    pub fn find<P>(&self, predicate: P) -> Option<GridPos>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.positions(predicate).next()
+    }
+
+    pub fn map<U, F>(&self, mut mapper: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(&mut mapper).collect(),
+        }
+    }
+
+    fn index_of(&self, pos: GridPos) -> Option<usize> {
+        self.in_bounds(pos).then_some(pos.1 * self.width + pos.0)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Mirrors the grid over its main diagonal; rows become columns
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height, self.width, |(x, y)| (y, x))
+    }
+
+    /// Rotates the grid a quarter turn clockwise
+    pub fn rotate_clockwise(&self) -> Self {
+        let height = self.height;
+        self.remap(self.height, self.width, |(x, y)| (y, height - 1 - x))
+    }
+
+    /// Rotates the grid a quarter turn counter clockwise
+    pub fn rotate_counter_clockwise(&self) -> Self {
+        let width = self.width;
+        self.remap(self.height, self.width, |(x, y)| (width - 1 - y, x))
+    }
+
+    /// Builds a grid of the given size, where each position is filled from `source(position)`
+    fn remap<F>(&self, width: usize, height: usize, source: F) -> Self
+    where
+        F: Fn(GridPos) -> GridPos,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|pos| self[source(pos)].clone())
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T> Index<GridPos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: GridPos) -> &Self::Output {
+        let index = self
+            .index_of(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", pos));
+        &self.cells[index]
+    }
+}
+
+impl<T> IndexMut<GridPos> for Grid<T> {
+    fn index_mut(&mut self, pos: GridPos) -> &mut Self::Output {
+        let index = self
+            .index_of(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", pos));
+        &mut self.cells[index]
+    }
+}
+
+impl<T> From<Vec<Vec<T>>> for Grid<T> {
+    fn from(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map(|r| r.len()).unwrap_or(0);
+        assert!(
+            rows.iter().all(|r| r.len() == width),
+            "All rows should have the same width"
+        );
+        Self {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+}
+
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+
+    const INPUT: &str = "ab.
+#cd";
+
+    fn grid() -> Grid<char> {
+        Grid::parse(INPUT, |c| c).unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'd');
+        assert_eq!(grid.get((3, 0)), None);
+        assert!(Grid::parse("ab\nc", |c| c).is_err());
+        assert_eq!(
+            Grid::try_parse("1x", |c| c.to_digit(10).ok_or("Not a digit")),
+            Err(String::from("Not a digit at 1,0"))
+        );
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbors8((1, 0)).collect::<Vec<_>>(),
+            [(2, 0), (2, 1), (1, 1), (0, 1), (0, 0)]
+        );
+        assert_eq!(grid.offset((0, 1), (-1, 0)), None);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = grid();
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            [['a', 'b', '.'], ['#', 'c', 'd']]
+        );
+        assert_eq!(
+            grid.columns()
+                .map(|c| c.collect::<String>())
+                .collect::<Vec<_>>(),
+            ["a#", "bc", ".d"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Column 3 is out of bounds for width 3")]
+    fn column_out_of_bounds() {
+        grid().column(3).count();
+    }
+
+    #[test]
+    fn transform() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "a#\nbc\n.d\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "#a\ncb\nd.\n");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), ".d\nbc\na#\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counter_clockwise(), grid);
+    }
+
+    #[test]
+    fn find() {
+        let grid = grid();
+        assert_eq!(grid.find(|c| *c == 'c'), Some((1, 1)));
+        assert_eq!(grid.find(|c| *c == 'x'), None);
+        assert_eq!(
+            grid.positions(|c| c.is_alphabetic()).collect::<Vec<_>>(),
+            [(0, 0), (1, 0), (1, 1), (2, 1)]
+        );
+    }
+}
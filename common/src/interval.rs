@@ -0,0 +1,347 @@
+use std::ops::{Range, RangeInclusive};
+
+use crate::geom::Integer;
+
+/// Half open interval `start..end`; empty when `start >= end`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Integer> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// Number of values in the interval
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::ZERO
+        } else {
+            self.end - self.start
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// Values in both intervals; may be empty
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Splits into the values below `at` and the values from `at` on; either may be empty
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        (
+            Self::new(self.start, at.min(self.end)),
+            Self::new(at.max(self.start), self.end),
+        )
+    }
+}
+
+impl<T: Integer> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+impl<T: Integer> From<Interval<T>> for Range<T> {
+    fn from(interval: Interval<T>) -> Self {
+        interval.start..interval.end
+    }
+}
+
+/// Inclusive interval `start..=end`; empty when `start > end`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct InclusiveInterval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Integer> InclusiveInterval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start > self.end
+    }
+
+    /// Number of values in the interval
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::ZERO
+        } else {
+            self.end - self.start + T::ONE
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// Values in both intervals; may be empty
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The same values as half open interval, or `None` if `end + 1` does not fit in `T`
+    pub fn to_half_open(&self) -> Option<Interval<T>> {
+        Some(Interval::new(self.start, self.end.checked_add(T::ONE)?))
+    }
+}
+
+impl<T: Integer> From<RangeInclusive<T>> for InclusiveInterval<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        Self::new(*range.start(), *range.end())
+    }
+}
+
+/// Set of values stored as sorted, disjoint and non adjacent half open intervals
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Integer> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { intervals: vec![] }
+    }
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The intervals of the set, in ascending order
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    /// Lowest value in the set
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    /// Total number of values in the set
+    pub fn measure(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::ZERO, |total, interval| total + interval.len())
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.intervals.partition_point(|i| i.end <= value);
+        self.intervals
+            .get(index)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        // all intervals touching or overlapping the new one are merged into it
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, i| {
+                Interval::new(merged.start.min(i.start), merged.end.max(i.end))
+            });
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        other.intervals.iter().for_each(|i| result.insert(*i));
+        result
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let (mut a, mut b) = (0, 0);
+        while a < self.intervals.len() && b < other.intervals.len() {
+            let (left, right) = (self.intervals[a], other.intervals[b]);
+            let overlap = left.intersection(&right);
+            if !overlap.is_empty() {
+                intervals.push(overlap);
+            }
+            if left.end < right.end {
+                a += 1;
+            } else {
+                b += 1;
+            }
+        }
+        Self { intervals }
+    }
+
+    /// Values in this set, but not in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let mut removals = other.intervals.iter().peekable();
+        for interval in &self.intervals {
+            let mut remaining = *interval;
+            while let Some(removal) = removals.peek() {
+                if removal.end <= remaining.start {
+                    removals.next();
+                    continue;
+                }
+                if removal.start >= remaining.end {
+                    break;
+                }
+                let (below, _) = remaining.split_at(removal.start);
+                if !below.is_empty() {
+                    intervals.push(below);
+                }
+                remaining = remaining.split_at(removal.end).1;
+                if remaining.is_empty() {
+                    break;
+                }
+            }
+            if !remaining.is_empty() {
+                intervals.push(remaining);
+            }
+        }
+        Self { intervals }
+    }
+
+    /// Splits into the values below `at` and the values from `at` on
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let (mut below, mut above) = (vec![], vec![]);
+        for interval in &self.intervals {
+            let (low, high) = interval.split_at(at);
+            if !low.is_empty() {
+                below.push(low);
+            }
+            if !high.is_empty() {
+                above.push(high);
+            }
+        }
+        (Self { intervals: below }, Self { intervals: above })
+    }
+}
+
+impl<T: Integer> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        Self::from_iter([interval])
+    }
+}
+
+impl<T: Integer> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        iter.into_iter().for_each(|i| set.insert(i));
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InclusiveInterval, Interval, IntervalSet};
+    use proptest::prelude::*;
+
+    const UNIVERSE: i32 = 64;
+
+    fn interval_set() -> impl Strategy<Value = IntervalSet<i32>> {
+        prop::collection::vec((0..UNIVERSE, 0..16), 0..8).prop_map(|intervals| {
+            intervals
+                .into_iter()
+                .map(|(start, len)| Interval::new(start, start + len))
+                .collect()
+        })
+    }
+
+    /// The set as plain list of values, to compare against
+    fn values(set: &IntervalSet<i32>) -> Vec<i32> {
+        (-1..UNIVERSE + 16).filter(|v| set.contains(*v)).collect()
+    }
+
+    fn is_normalized(set: &IntervalSet<i32>) -> bool {
+        set.intervals().iter().all(|i| !i.is_empty())
+            && set.intervals().windows(2).all(|w| w[0].end < w[1].start)
+    }
+
+    proptest! {
+        #[test]
+        fn union(a in interval_set(), b in interval_set()) {
+            let union = a.union(&b);
+            prop_assert!(is_normalized(&union));
+            for v in -1..UNIVERSE + 16 {
+                prop_assert_eq!(union.contains(v), a.contains(v) || b.contains(v));
+            }
+        }
+
+        #[test]
+        fn intersection(a in interval_set(), b in interval_set()) {
+            let intersection = a.intersection(&b);
+            prop_assert!(is_normalized(&intersection));
+            for v in -1..UNIVERSE + 16 {
+                prop_assert_eq!(intersection.contains(v), a.contains(v) && b.contains(v));
+            }
+        }
+
+        #[test]
+        fn difference(a in interval_set(), b in interval_set()) {
+            let difference = a.difference(&b);
+            prop_assert!(is_normalized(&difference));
+            for v in -1..UNIVERSE + 16 {
+                prop_assert_eq!(difference.contains(v), a.contains(v) && !b.contains(v));
+            }
+        }
+
+        #[test]
+        fn split_and_measure(a in interval_set(), at in -1..UNIVERSE + 16) {
+            let (below, above) = a.split_at(at);
+            prop_assert!(values(&below).iter().all(|v| *v < at));
+            prop_assert!(values(&above).iter().all(|v| *v >= at));
+            prop_assert_eq!(below.union(&above), a.clone());
+            prop_assert_eq!(below.measure() + above.measure(), a.measure());
+            prop_assert_eq!(a.measure() as usize, values(&a).len());
+        }
+    }
+
+    #[test]
+    fn intervals() {
+        let interval = Interval::new(2, 6);
+        assert_eq!(interval.len(), 4);
+        assert_eq!(
+            interval.split_at(4),
+            (Interval::new(2, 4), Interval::new(4, 6))
+        );
+        assert!(interval.split_at(10).1.is_empty());
+        assert!(!interval.overlaps(&Interval::new(6, 8)));
+        let inclusive = InclusiveInterval::from(2..=6);
+        assert_eq!(inclusive.len(), 5);
+        assert!(inclusive.overlaps(&InclusiveInterval::new(6, 8)));
+        assert_eq!(inclusive.to_half_open(), Some(Interval::new(2, 7)));
+        assert_eq!(InclusiveInterval::new(0, u8::MAX).to_half_open(), None);
+    }
+
+    #[test]
+    fn merges_adjacent() {
+        let set = IntervalSet::from_iter([Interval::new(0, 2), Interval::new(4, 6)]);
+        let set = set.union(&Interval::new(2, 4).into());
+        assert_eq!(set.intervals(), [Interval::new(0, 6)]);
+        assert_eq!(set.min(), Some(0));
+    }
+}
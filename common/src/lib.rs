@@ -1,3 +1,11 @@
+pub mod cycle;
+pub mod geom;
+pub mod grid;
+pub mod interval;
+pub mod math;
+pub mod parse;
+pub mod search;
 pub(crate) mod solution;
 
+pub use self::grid::{Grid, GridPos};
 pub use self::solution::*;
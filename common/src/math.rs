@@ -0,0 +1,369 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use crate::geom::{Integer, Point2};
+
+/// Greatest common divisor; never negative, `gcd(0, 0)` is 0
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs_diff(T::ZERO), b.abs_diff(T::ZERO));
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple; never negative, 0 if any of the values is 0
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+    (a / gcd(a, b) * b).abs_diff(T::ZERO)
+}
+
+pub fn gcd_all<T: Integer, I: IntoIterator<Item = T>>(values: I) -> T {
+    values.into_iter().fold(T::ZERO, gcd)
+}
+
+/// Least common multiple of all values; 1 for no values
+pub fn lcm_all<T: Integer, I: IntoIterator<Item = T>>(values: I) -> T {
+    values.into_iter().fold(T::ONE, lcm)
+}
+
+/// Returns `(g, x, y)` where `g = gcd(a, b)` and `a * x + b * y = g`
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Chinese remainder theorem for `x = residue (mod modulus)` congruences, where the moduli do not
+/// have to be coprime. Returns the smallest non negative solution and the combined modulus, or
+/// `None` if the congruences contradict each other.
+pub fn crt<I: IntoIterator<Item = (i128, i128)>>(congruences: I) -> Option<(i128, i128)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(residue, modulus), (r, m)| {
+            let (g, p, _) = extended_gcd(modulus, m);
+            let difference = r - residue;
+            if difference % g != 0 {
+                return None;
+            }
+            let combined = modulus / g * m;
+            let step = (difference / g * p).rem_euclid(m / g);
+            Some(((residue + modulus * step).rem_euclid(combined), combined))
+        })
+}
+
+/// Largest integer whose square is not larger than `n`
+pub fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    // Newton's method, starting above the root so it descends monotonically
+    let mut x = 1 << (n.ilog2() / 2 + 1);
+    loop {
+        let next = (x + n / x) / 2;
+        if next >= x {
+            return x;
+        }
+        x = next;
+    }
+}
+
+/// Sum of `floor((a * i + b) / m)` for `i` in `0..n`; `a` and `b` must not be negative and `m`
+/// has to be positive. Runs in logarithmic time, like Euclid's algorithm.
+pub fn floor_sum(n: i128, m: i128, a: i128, b: i128) -> i128 {
+    let (mut n, mut m, mut a, mut b) = (n, m, a, b);
+    let mut sum = 0;
+    while n > 0 {
+        if a >= m {
+            sum += n * (n - 1) / 2 * (a / m);
+            a %= m;
+        }
+        if b >= m {
+            sum += n * (b / m);
+            b %= m;
+        }
+        // count the lattice points below the line again, with the axes swapped
+        let y_max = a * n + b;
+        if y_max < m {
+            break;
+        }
+        (n, b) = (y_max / m, y_max % m);
+        (m, a) = (a, m);
+    }
+    sum
+}
+
+/// Exact fraction, always stored in lowest terms with a positive denominator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    pub fn new(numerator: i128, denominator: i128) -> Self {
+        assert!(denominator != 0, "Denominator cannot be 0");
+        let divisor = gcd(numerator, denominator) * denominator.signum();
+        Self {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    /// The value as integer, if it is one
+    pub fn to_integer(&self) -> Option<i128> {
+        (self.denominator == 1).then_some(self.numerator)
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(value: i128) -> Self {
+        Self {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.numerator * rhs.denominator + rhs.numerator * self.denominator,
+            self.denominator * rhs.denominator,
+        )
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.numerator * rhs.numerator,
+            self.denominator * rhs.denominator,
+        )
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.numerator * rhs.denominator,
+            self.denominator * rhs.numerator,
+        )
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.numerator * other.denominator).cmp(&(other.numerator * self.denominator))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.denominator {
+            1 => write!(f, "{}", self.numerator),
+            d => write!(f, "{}/{}", self.numerator, d),
+        }
+    }
+}
+
+/// Evaluates the lowest degree polynomial through all `(x, y)` points at `x`
+pub fn lagrange_extrapolate(points: &[(i128, i128)], x: i128) -> Rational {
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, &(xi, yi))| {
+            points
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .fold(Rational::from(yi), |term, (_, &(xj, _))| {
+                    term * Rational::new(x - xj, xi - xj)
+                })
+        })
+        .fold(Rational::from(0), |sum, term| sum + term)
+}
+
+/// Evaluates the lowest degree polynomial through `samples`, taken at `x = 0, 1, 2, ...`, at `x`;
+/// uses Newton's forward differences
+pub fn newton_extrapolate(samples: &[i128], x: i128) -> Rational {
+    let mut differences = samples.to_vec();
+    let mut result = Rational::from(0);
+    // binomial coefficient (x choose k), built up one k at a time
+    let mut coefficient = Rational::from(1);
+    for k in 0..samples.len() {
+        result = result + coefficient * Rational::from(differences[0]);
+        coefficient = coefficient * Rational::new(x - k as i128, k as i128 + 1);
+        differences = differences.windows(2).map(|w| w[1] - w[0]).collect();
+    }
+    result
+}
+
+/// Twice the area enclosed by a polygon; the last vertex connects back to the first
+pub fn shoelace_double_area(vertices: &[Point2<i64>]) -> i64 {
+    let sum: i64 = vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(a, b)| a.x * b.y - a.y * b.x)
+        .sum();
+    sum.abs()
+}
+
+/// Number of lattice points on the edges of a polygon with lattice vertices
+pub fn boundary_points(vertices: &[Point2<i64>]) -> i64 {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(a, b)| gcd(a.x - b.x, a.y - b.y))
+        .sum()
+}
+
+/// Pick's theorem; lattice points strictly inside a polygon with lattice vertices
+pub fn interior_points(double_area: i64, boundary_points: i64) -> i64 {
+    (double_area - boundary_points + 2) / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12_i64, 18), 6);
+        assert_eq!(gcd(0_usize, 5), 5);
+        assert_eq!(lcm(4_usize, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(gcd_all([12_u32, 18, 30]), 6);
+        assert_eq!(lcm_all([3_usize, 4, 5]), 60);
+        assert_eq!(lcm_all(Vec::<usize>::new()), 1);
+    }
+
+    #[test]
+    fn extended_gcd_identity() {
+        for (a, b) in [(240, 46), (-7, 3), (0, 5), (17, 0)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a, b));
+            assert_eq!(a * x + b * y, g);
+        }
+    }
+
+    #[test]
+    fn chinese_remainder() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // non coprime moduli
+        assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([]), Some((0, 1)));
+    }
+
+    #[test]
+    fn integer_square_root() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(15), 3);
+        assert_eq!(isqrt(16), 4);
+        assert_eq!(isqrt(u64::MAX as u128), u32::MAX as u128);
+        assert_eq!(isqrt(u128::MAX), u64::MAX as u128);
+    }
+
+    #[test]
+    fn floor_sums() {
+        for (n, m, a, b) in [(0, 3, 1, 1), (10, 7, 3, 5), (25, 4, 9, 0), (13, 13, 1, 12)] {
+            let expected: i128 = (0..n).map(|i| (a * i + b) / m).sum();
+            assert_eq!(floor_sum(n, m, a, b), expected);
+        }
+    }
+
+    #[test]
+    fn rationals() {
+        let half = Rational::new(2, -4);
+        assert_eq!(half, Rational::new(-1, 2));
+        assert_eq!((half + Rational::from(1)).to_string(), "1/2");
+        assert_eq!((half * Rational::from(-2)).to_integer(), Some(1));
+        assert_eq!(half / Rational::new(1, 4), Rational::from(-2));
+        assert!(half < Rational::from(0));
+    }
+
+    #[test]
+    fn extrapolation() {
+        // x^2 + 1
+        let points = [(1, 2), (2, 5), (4, 17)];
+        assert_eq!(lagrange_extrapolate(&points, 3), Rational::from(10));
+        assert_eq!(lagrange_extrapolate(&points, -1), Rational::from(2));
+        let samples = [1, 3, 6, 10, 15, 21];
+        assert_eq!(newton_extrapolate(&samples, 6), Rational::from(28));
+        assert_eq!(newton_extrapolate(&samples, -1), Rational::from(0));
+        assert_eq!(newton_extrapolate(&[0, 1], 1), Rational::from(1));
+    }
+
+    #[test]
+    fn polygons() {
+        let square = [(0, 0), (4, 0), (4, 4), (0, 4)].map(Point2::from);
+        assert_eq!(shoelace_double_area(&square), 32);
+        assert_eq!(boundary_points(&square), 16);
+        assert_eq!(interior_points(32, 16), 9);
+        let triangle = [(0, 0), (3, 0), (0, 3)].map(Point2::from);
+        assert_eq!(shoelace_double_area(&triangle), 9);
+        assert_eq!(boundary_points(&triangle), 9);
+        assert_eq!(interior_points(9, 9), 1);
+    }
+}
@@ -0,0 +1,319 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+use crate::{grid::GridParseError, Grid};
+
+/// Parse failure at a position in the input; line and column are 1 based
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<ParseError> for String {
+    fn from(error: ParseError) -> Self {
+        error.to_string()
+    }
+}
+
+/// (Part of) a line of input, which remembers where it came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub column: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: &'a str) -> Self {
+        Self {
+            number,
+            column: 1,
+            text,
+        }
+    }
+
+    /// Error pointing at `offset` bytes into this part of the line
+    pub fn error_at<M: Display>(&self, offset: usize, message: M) -> ParseError {
+        ParseError {
+            line: self.number,
+            column: self.column + self.text[..offset.min(self.text.len())].chars().count(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn error<M: Display>(&self, message: M) -> ParseError {
+        self.error_at(0, message)
+    }
+
+    /// `part` has to be a slice of this line's text
+    fn sub(&self, part: &'a str) -> Self {
+        let offset = part.as_ptr() as usize - self.text.as_ptr() as usize;
+        Self {
+            number: self.number,
+            column: self.column + self.text[..offset].chars().count(),
+            text: part,
+        }
+    }
+
+    pub fn trim(&self) -> Self {
+        self.sub(self.text.trim())
+    }
+
+    pub fn parse<T>(&self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let trimmed = self.trim();
+        trimmed
+            .text
+            .parse()
+            .map_err(|e| trimmed.error(format!("Invalid value '{}': {e}", trimmed.text)))
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Self, ParseError> {
+        match self.text.strip_prefix(prefix) {
+            Some(rest) => Ok(self.sub(rest)),
+            None => Err(self.error(format!("Expected '{prefix}'"))),
+        }
+    }
+
+    pub fn split_once(&self, separator: &str) -> Result<(Self, Self), ParseError> {
+        match self.text.split_once(separator) {
+            Some((left, right)) => Ok((self.sub(left), self.sub(right))),
+            None => Err(self.error(format!("Expected '{separator}'"))),
+        }
+    }
+
+    /// Splits a `key: values` line, the value keeps its position for later errors
+    pub fn key_value(&self) -> Result<(&'a str, Self), ParseError> {
+        let (key, value) = self.split_once(":")?;
+        Ok((key.text.trim(), value.trim()))
+    }
+
+    /// Splits at `separator`, trimming the parts and skipping empty ones
+    pub fn split(&self, separator: &'a str) -> impl Iterator<Item = Line<'a>> + 'a {
+        let line = *self;
+        self.text
+            .split(separator)
+            .map(move |part| line.sub(part).trim())
+            .filter(|part| !part.text.is_empty())
+    }
+
+    /// Parses every part between `separator`s
+    pub fn list<T>(&self, separator: &'a str) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.split(separator).map(|part| part.parse()).collect()
+    }
+
+    /// Parses every whitespace separated word
+    pub fn words<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let line = *self;
+        self.text
+            .split_whitespace()
+            .map(|word| line.sub(word).parse())
+            .collect()
+    }
+
+    /// Every integer in the line, ignoring everything in between; a `-` directly in front of a
+    /// number is its sign, unless it follows a digit as in `1-3`
+    pub fn integers<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let bytes = self.text.as_bytes();
+        let mut integers = vec![];
+        let mut index = 0;
+        while index < bytes.len() {
+            let is_sign = bytes[index] == b'-'
+                && bytes.get(index + 1).is_some_and(u8::is_ascii_digit)
+                && (index == 0 || !bytes[index - 1].is_ascii_digit());
+            if !is_sign && !bytes[index].is_ascii_digit() {
+                index += 1;
+                continue;
+            }
+            let start = index;
+            index += 1;
+            while index < bytes.len() && bytes[index].is_ascii_digit() {
+                index += 1;
+            }
+            integers.push(self.sub(&self.text[start..index]).parse()?);
+        }
+        Ok(integers)
+    }
+}
+
+/// Lines of the input, numbered from 1
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, text)| Line::new(index + 1, text))
+}
+
+/// Group of consecutive non blank lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Block<'a> {
+    pub first_line: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Block<'a> {
+    pub fn lines(&self) -> impl Iterator<Item = Line<'a>> {
+        let first_line = self.first_line;
+        self.text
+            .lines()
+            .enumerate()
+            .map(move |(index, text)| Line::new(first_line + index, text))
+    }
+
+    /// First line of the block, commonly a header
+    pub fn first(&self) -> Line<'a> {
+        Line::new(
+            self.first_line,
+            self.text.lines().next().unwrap_or_default(),
+        )
+    }
+
+    /// Grid of cells, one per character
+    pub fn grid<T, E, F>(&self, cell: F) -> Result<Grid<T>, ParseError>
+    where
+        E: Display,
+        F: FnMut(char) -> Result<T, E>,
+    {
+        Grid::parse_cells(self.text, cell).map_err(|e| match e {
+            GridParseError::Cell {
+                pos: (x, y),
+                message,
+            } => ParseError {
+                line: self.first_line + y,
+                column: x + 1,
+                message,
+            },
+            GridParseError::Width { y, width, expected } => ParseError {
+                line: self.first_line + y,
+                column: 1,
+                message: format!("Line has width {width}, expected {expected}"),
+            },
+        })
+    }
+}
+
+/// Splits the input at blank lines
+pub fn blocks(input: &str) -> Vec<Block<'_>> {
+    let mut blocks = vec![];
+    let mut current: Option<(usize, usize)> = None;
+    for (index, text) in input.lines().enumerate() {
+        let offset = text.as_ptr() as usize - input.as_ptr() as usize;
+        if text.trim().is_empty() {
+            if let Some((first_line, start)) = current.take() {
+                blocks.push(Block {
+                    first_line,
+                    text: input[start..offset].trim_end(),
+                });
+            }
+        } else if current.is_none() {
+            current = Some((index + 1, offset));
+        }
+    }
+    if let Some((first_line, start)) = current {
+        blocks.push(Block {
+            first_line,
+            text: input[start..].trim_end(),
+        });
+    }
+    blocks
+}
+
+/// Grid of cells, one per character of the whole input
+pub fn grid<T, E, F>(input: &str, cell: F) -> Result<Grid<T>, ParseError>
+where
+    E: Display,
+    F: FnMut(char) -> Result<T, E>,
+{
+    Block {
+        first_line: 1,
+        text: input.trim_end(),
+    }
+    .grid(cell)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers() {
+        let line = Line::new(1, "p=-3,14 v=2-5 x-7");
+        assert_eq!(line.integers::<i32>(), Ok(vec![-3, 14, 2, 5, -7]));
+        let error = Line::new(4, "a 300").integers::<u8>().unwrap_err();
+        assert_eq!((error.line, error.column), (4, 3));
+    }
+
+    #[test]
+    fn key_values_and_lists() {
+        let line = Line::new(2, "Game 12: 3 blue, 4 red");
+        let (key, value) = line.key_value().unwrap();
+        assert_eq!(key, "Game 12");
+        assert_eq!(value.column, 10);
+        assert_eq!(key.strip_prefix("Game "), Some("12"));
+        let items = value.split(",").collect::<Vec<_>>();
+        assert_eq!(items[1].text, "4 red");
+        assert_eq!(items[1].column, 18);
+        assert_eq!(Line::new(1, "1 2  3").words::<u32>(), Ok(vec![1, 2, 3]));
+        let error = Line::new(3, "1, x, 3").list::<u32>(",").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 4));
+        assert_eq!(
+            error.to_string(),
+            "Line 3, column 4: Invalid value 'x': invalid digit found in string"
+        );
+        assert!(Line::new(1, "no separator").key_value().is_err());
+    }
+
+    #[test]
+    fn blocks_and_grids() {
+        let input = "header\n#.\n.#\n\n\n##\n.x\n";
+        let blocks = blocks(input);
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].first().text, "header");
+        assert_eq!(blocks[1].first_line, 6);
+        let cell = |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            c => Err(format!("Unknown cell '{c}'")),
+        };
+        let error = blocks[1].grid(cell).unwrap_err();
+        assert_eq!((error.line, error.column), (7, 2));
+        let grid = grid("#.\n.#\n", cell).unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert!(grid[(1, 1)]);
+        let error = grid_error("##\n#\n");
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.message, "Line has width 1, expected 2");
+    }
+
+    fn grid_error(input: &str) -> ParseError {
+        grid(input, Ok::<char, String>).unwrap_err()
+    }
+}
@@ -0,0 +1,191 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+use crate::geom::Integer;
+
+/// Cost of the cheapest route to a goal, with the states along the way; including start and goal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchResult<S, C> {
+    pub cost: C,
+    pub path: Vec<S>,
+}
+
+/// Breadth first search, where every step costs 1
+pub fn bfs<S, FN, IN, FG>(
+    start: S,
+    mut successors: FN,
+    mut goal: FG,
+) -> Option<SearchResult<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = S>,
+    FG: FnMut(&S) -> bool,
+{
+    let mut visited = Visited::new(start.clone(), 0);
+    let mut queue = VecDeque::from([0]);
+    while let Some(index) = queue.pop_front() {
+        let state = visited.states[index].clone();
+        if goal(&state) {
+            return Some(visited.result(index));
+        }
+        let cost = visited.costs[index] + 1;
+        for next in successors(&state) {
+            if let Some(next_index) = visited.improve(next, cost, index) {
+                queue.push_back(next_index);
+            }
+        }
+    }
+    None
+}
+
+/// Cheapest route search, for successors with a non negative cost
+pub fn dijkstra<S, C, FN, IN, FG>(start: S, successors: FN, goal: FG) -> Option<SearchResult<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Integer,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+    FG: FnMut(&S) -> bool,
+{
+    astar(start, successors, |_| C::ZERO, goal)
+}
+
+/// Cheapest route search guided by a heuristic, which should never overestimate the remaining cost
+pub fn astar<S, C, FN, IN, FH, FG>(
+    start: S,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut goal: FG,
+) -> Option<SearchResult<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Integer,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+    FH: FnMut(&S) -> C,
+    FG: FnMut(&S) -> bool,
+{
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), C::ZERO, 0))]);
+    let mut visited = Visited::new(start, C::ZERO);
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        if cost > visited.costs[index] {
+            // a cheaper route to this state was found after queueing it
+            continue;
+        }
+        let state = visited.states[index].clone();
+        if goal(&state) {
+            return Some(visited.result(index));
+        }
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            let estimate = next_cost + heuristic(&next);
+            if let Some(next_index) = visited.improve(next, next_cost, index) {
+                queue.push(Reverse((estimate, next_cost, next_index)));
+            }
+        }
+    }
+    None
+}
+
+/// Every state seen so far, with the cheapest known cost and the state it was reached from
+struct Visited<S, C> {
+    indices: HashMap<S, usize>,
+    states: Vec<S>,
+    costs: Vec<C>,
+    parents: Vec<usize>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy + Ord> Visited<S, C> {
+    fn new(start: S, cost: C) -> Self {
+        Self {
+            indices: HashMap::from([(start.clone(), 0)]),
+            states: vec![start],
+            costs: vec![cost],
+            parents: vec![0],
+        }
+    }
+
+    /// Records the route to the state if it is the cheapest so far; returns the index of the state if so
+    fn improve(&mut self, state: S, cost: C, parent: usize) -> Option<usize> {
+        match self.indices.get(&state) {
+            Some(&index) if self.costs[index] <= cost => None,
+            Some(&index) => {
+                self.costs[index] = cost;
+                self.parents[index] = parent;
+                Some(index)
+            }
+            None => {
+                let index = self.states.len();
+                self.indices.insert(state.clone(), index);
+                self.states.push(state);
+                self.costs.push(cost);
+                self.parents.push(parent);
+                Some(index)
+            }
+        }
+    }
+
+    fn result(&self, mut index: usize) -> SearchResult<S, C> {
+        let cost = self.costs[index];
+        let mut path = vec![self.states[index].clone()];
+        while index != 0 {
+            index = self.parents[index];
+            path.push(self.states[index].clone());
+        }
+        path.reverse();
+        SearchResult { cost, path }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{astar, bfs, dijkstra};
+    use crate::Grid;
+
+    const MAZE: &str = "S.#.
+.##.
+...G";
+
+    #[test]
+    fn bfs_finds_shortest_path() {
+        let grid = Grid::parse(MAZE, |c| c).unwrap();
+        let result = bfs(
+            (0, 0),
+            |pos| {
+                grid.neighbors4(*pos)
+                    .filter(|n| grid[*n] != '#')
+                    .collect::<Vec<_>>()
+            },
+            |pos| grid[*pos] == 'G',
+        )
+        .unwrap();
+        assert_eq!(result.cost, 5);
+        assert_eq!(
+            result.path,
+            [(0, 0), (0, 1), (0, 2), (1, 2), (2, 2), (3, 2)]
+        );
+        assert_eq!(bfs(0, |n| [n + 1], |n| *n == 5).map(|r| r.cost), Some(5));
+        assert_eq!(bfs(0, |_| [], |n| *n == 1), None);
+    }
+
+    #[test]
+    fn dijkstra_prefers_cheap_edges() {
+        // 0 -> 1 -> 2 -> 3 costs 3, the direct edge 0 -> 3 costs 10
+        let edges = |n: &u32| match n {
+            0 => vec![(1, 1), (3, 10_u32)],
+            1 => vec![(2, 1)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        };
+        let result = dijkstra(0, edges, |n| *n == 3).unwrap();
+        assert_eq!(result.cost, 3);
+        assert_eq!(result.path, [0, 1, 2, 3]);
+        let result = astar(0, edges, |n| 3 - n.min(&3), |n| *n == 3).unwrap();
+        assert_eq!(result.cost, 3);
+        assert_eq!(result.path, [0, 1, 2, 3]);
+    }
+}
@@ -54,11 +54,13 @@ macro_rules! from_numeric_to_answer {
     };
 }
 from_numeric_to_answer!(usize);
+from_numeric_to_answer!(u128);
 from_numeric_to_answer!(u64);
 from_numeric_to_answer!(u32);
 from_numeric_to_answer!(u16);
 from_numeric_to_answer!(u8);
 from_numeric_to_answer!(isize);
+from_numeric_to_answer!(i128);
 from_numeric_to_answer!(i64);
 from_numeric_to_answer!(i32);
 from_numeric_to_answer!(i16);
@@ -1,9 +1,19 @@
 use common::Answer;
-use super::Solution;
+use super::{PuzzleInfo, Registration, Solution};
 
 #[derive(Default)]
 pub struct Puzzle;
 
+pub(super) const REGISTRATION: Registration = Registration::new::<Puzzle>(PuzzleInfo {
+    year: 2023,
+    day: 0,
+    title: "",
+    has_visualization: false,
+    parameters: &[],
+    assumptions: &[],
+    exports: &[],
+});
+
 impl Solution for Puzzle {
     fn solve_a(&mut self, _input: String) -> Result<Answer, String> {
         Answer::from("").into()
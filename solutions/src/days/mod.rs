@@ -1,36 +1,38 @@
 use common::Answer;
 
-mod day_01;
-mod day_02;
-mod day_03;
-mod day_04;
-mod day_05;
-mod day_06;
-mod day_07;
-mod day_08;
-mod day_09;
-mod day_10;
-mod day_11;
-mod day_12;
-mod day_13;
-mod day_14;
-mod day_15;
-mod day_16;
-mod day_17;
-mod day_18;
-mod day_19;
-mod day_20;
-mod day_21;
-mod day_22;
-mod day_23;
-mod day_24;
-mod day_25;
+mod parameters;
+mod registry;
+
+pub use self::parameters::*;
+pub use self::registry::*;
+
+/// Collects the registrations of a year's day modules, in calendar order.
+macro_rules! register_days {
+    ($($module:ident),* $(,)?) => {
+        pub(super) static REGISTRY: &[Registration] = &[$($module::REGISTRATION),*];
+    };
+}
+
+pub(crate) mod y2023;
+
+/// Registrations per year, in chronological order
+static YEARS: &[&[Registration]] = &[y2023::REGISTRY];
 
 #[async_trait::async_trait]
 pub trait Solution {
     fn solve_a(&mut self, input: String) -> Result<Answer, String>;
     fn solve_b(&mut self, input: String) -> Result<Answer, String>;
 
+    /// Tunable parameters of the puzzle, as declared in its registration
+    fn parameters(&mut self) -> Vec<ParameterField<'_>> {
+        vec![]
+    }
+
+    /// Contents of the export with the given name, as declared in its registration
+    fn export(&mut self, _input: String, name: &str) -> Result<String, String> {
+        Err(format!("Unknown export: {}", name))
+    }
+
     #[cfg(feature = "ui")]
     fn get_shapes(
         &mut self,
@@ -39,33 +41,83 @@ pub trait Solution {
     ) -> Option<ui_support::DisplayResult>;
 }
 
-pub fn get_day(day: u8) -> Result<Box<dyn Solution + Send>, String> {
-    match day {
-        1 => Ok(Box::<day_01::Puzzle>::default()),
-        2 => Ok(Box::<day_02::Puzzle>::default()),
-        3 => Ok(Box::<day_03::Puzzle>::default()),
-        4 => Ok(Box::<day_04::Puzzle>::default()),
-        5 => Ok(Box::<day_05::Puzzle>::default()),
-        6 => Ok(Box::<day_06::Puzzle>::default()),
-        7 => Ok(Box::<day_07::Puzzle>::default()),
-        8 => Ok(Box::<day_08::Puzzle>::default()),
-        9 => Ok(Box::<day_09::Puzzle>::default()),
-        10 => Ok(Box::<day_10::Puzzle>::default()),
-        11 => Ok(Box::<day_11::Puzzle>::default()),
-        12 => Ok(Box::<day_12::Puzzle>::default()),
-        13 => Ok(Box::<day_13::Puzzle>::default()),
-        14 => Ok(Box::<day_14::Puzzle>::default()),
-        15 => Ok(Box::<day_15::Puzzle>::default()),
-        16 => Ok(Box::<day_16::Puzzle>::default()),
-        17 => Ok(Box::<day_17::Puzzle>::default()),
-        18 => Ok(Box::<day_18::Puzzle>::default()),
-        19 => Ok(Box::<day_19::Puzzle>::default()),
-        20 => Ok(Box::<day_20::Puzzle>::default()),
-        21 => Ok(Box::<day_21::Puzzle>::default()),
-        22 => Ok(Box::<day_22::Puzzle>::default()),
-        23 => Ok(Box::<day_23::Puzzle>::default()),
-        24 => Ok(Box::<day_24::Puzzle>::default()),
-        25 => Ok(Box::<day_25::Puzzle>::default()),
-        _ => Err(String::from("Day not yet created")),
+/// All registered puzzles, ordered by year and day
+pub fn registry() -> impl Iterator<Item = &'static Registration> {
+    YEARS.iter().flat_map(|year| year.iter())
+}
+
+/// All years that have at least one registered puzzle
+pub fn years() -> Vec<u16> {
+    YEARS
+        .iter()
+        .filter_map(|year| year.first().map(|r| r.info.year))
+        .collect()
+}
+
+/// The most recent registered year
+pub fn latest_year() -> u16 {
+    *years().last().expect("No years registered")
+}
+
+pub fn registry_for_year(year: u16) -> impl Iterator<Item = &'static Registration> {
+    registry().filter(move |r| r.info.year == year)
+}
+
+pub fn get_registration(year: u16, day: u8) -> Option<&'static Registration> {
+    registry_for_year(year).find(|r| r.info.day == day)
+}
+
+pub fn get_day(year: u16, day: u8) -> Result<Box<dyn Solution + Send>, String> {
+    get_registration(year, day)
+        .map(|r| r.create())
+        .ok_or(format!("Day {} of {} not yet created", day, year))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{registry, registry_for_year, years, YEARS};
+
+    #[test]
+    fn registry_is_ordered_and_unique() {
+        for year in years() {
+            let days: Vec<u8> = registry_for_year(year).map(|r| r.info.day).collect();
+            let mut sorted = days.clone();
+            sorted.sort();
+            sorted.dedup();
+            assert_eq!(
+                days, sorted,
+                "Days of {} should be ordered and unique",
+                year
+            );
+        }
+        let mut sorted_years = years();
+        sorted_years.sort();
+        sorted_years.dedup();
+        assert_eq!(years(), sorted_years);
+        assert_eq!(years().len(), YEARS.len());
+    }
+
+    #[test]
+    fn registrations_match_their_year() {
+        for year in YEARS {
+            let first_year = year[0].info.year;
+            assert!(year.iter().all(|r| r.info.year == first_year));
+            assert!(year.iter().all(|r| !r.info.title.is_empty()));
+        }
+    }
+
+    #[test]
+    fn parameters_match_registration() {
+        for registration in registry() {
+            let mut solution = registration.create();
+            let names: Vec<&str> = solution.parameters().iter().map(|p| p.name).collect();
+            let declared: Vec<&str> = registration
+                .info
+                .parameters
+                .iter()
+                .map(|p| p.name)
+                .collect();
+            assert_eq!(names, declared, "Day {}", registration.info.day);
+        }
     }
 }
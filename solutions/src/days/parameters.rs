@@ -0,0 +1,95 @@
+use std::fmt::Display;
+
+use super::Solution;
+
+/// A tunable value of a puzzle, borrowed from the puzzle itself
+pub enum ParameterValue<'a> {
+    Unsigned(&'a mut usize),
+    Signed(&'a mut i64),
+    Float(&'a mut f64),
+}
+
+/// Named parameter as exposed by `Solution::parameters`
+pub struct ParameterField<'a> {
+    pub name: &'static str,
+    pub value: ParameterValue<'a>,
+}
+
+impl<'a> ParameterField<'a> {
+    pub fn new_unsigned(name: &'static str, value: &'a mut usize) -> Self {
+        Self {
+            name,
+            value: ParameterValue::Unsigned(value),
+        }
+    }
+
+    pub fn new_signed(name: &'static str, value: &'a mut i64) -> Self {
+        Self {
+            name,
+            value: ParameterValue::Signed(value),
+        }
+    }
+
+    pub fn new_float(name: &'static str, value: &'a mut f64) -> Self {
+        Self {
+            name,
+            value: ParameterValue::Float(value),
+        }
+    }
+
+    /// Parses the value according to the type of the parameter; `_` may be used as digit separator
+    pub fn set_from_str(&mut self, value: &str) -> Result<(), String> {
+        let cleaned = value.trim().replace('_', "");
+        let invalid =
+            |e: &dyn Display| format!("Invalid value for {}: {} ({})", self.name, value, e);
+        match &mut self.value {
+            ParameterValue::Unsigned(v) => **v = cleaned.parse().map_err(|e| invalid(&e))?,
+            ParameterValue::Signed(v) => **v = cleaned.parse().map_err(|e| invalid(&e))?,
+            ParameterValue::Float(v) => **v = cleaned.parse().map_err(|e| invalid(&e))?,
+        }
+        Ok(())
+    }
+}
+
+impl Display for ParameterField<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.value {
+            ParameterValue::Unsigned(v) => write!(f, "{}={}", self.name, v),
+            ParameterValue::Signed(v) => write!(f, "{}={}", self.name, v),
+            ParameterValue::Float(v) => write!(f, "{}={}", self.name, v),
+        }
+    }
+}
+
+/// Sets a parameter of a solution by name, parsing the value from text
+pub fn set_parameter(solution: &mut dyn Solution, name: &str, value: &str) -> Result<(), String> {
+    solution
+        .parameters()
+        .into_iter()
+        .find(|p| p.name == name)
+        .ok_or(format!("Unknown parameter: {}", name))?
+        .set_from_str(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParameterField;
+
+    #[test]
+    fn set_from_str() {
+        let mut steps = 64_usize;
+        let mut area = 7.0_f64;
+        let mut offset = 0_i64;
+        let mut field = ParameterField::new_unsigned("steps", &mut steps);
+        assert_eq!(field.set_from_str("1_000"), Ok(()));
+        assert!(field.set_from_str("-1").is_err());
+        assert_eq!(field.to_string(), "steps=1000");
+        let mut field = ParameterField::new_float("area", &mut area);
+        assert_eq!(field.set_from_str("2e14"), Ok(()));
+        let mut field = ParameterField::new_signed("offset", &mut offset);
+        assert_eq!(field.set_from_str("-2_000"), Ok(()));
+        assert_eq!(steps, 1000);
+        assert_eq!(offset, -2000);
+        assert_eq!(area, 200000000000000.0);
+    }
+}
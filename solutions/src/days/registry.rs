@@ -0,0 +1,60 @@
+use super::Solution;
+
+/// Static description of a puzzle, declared by the day module itself.
+#[derive(Debug)]
+pub struct PuzzleInfo {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    /// Whether `get_shapes` produces something to draw
+    pub has_visualization: bool,
+    pub parameters: &'static [Parameter],
+    /// Properties of the input the solution relies on, which are not guaranteed by the puzzle text
+    pub assumptions: &'static [&'static str],
+    /// Files `Solution::export` can write
+    pub exports: &'static [Export],
+}
+
+/// A tunable constant of a puzzle
+#[derive(Debug)]
+pub struct Parameter {
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+/// A file a puzzle can write for inspection in other tools
+#[derive(Debug)]
+pub struct Export {
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+/// Entry in the solution registry; links the puzzle info to its implementation.
+pub struct Registration {
+    pub info: PuzzleInfo,
+    factory: fn() -> Box<dyn Solution + Send>,
+}
+
+impl Registration {
+    pub const fn new<T>(info: PuzzleInfo) -> Self
+    where
+        T: Solution + Send + Default + 'static,
+    {
+        Self {
+            info,
+            factory: create::<T>,
+        }
+    }
+
+    /// Creates a fresh instance of the solution
+    pub fn create(&self) -> Box<dyn Solution + Send> {
+        (self.factory)()
+    }
+}
+
+fn create<T>() -> Box<dyn Solution + Send>
+where
+    T: Solution + Send + Default + 'static,
+{
+    Box::<T>::default()
+}
@@ -1,9 +1,19 @@
-use super::Solution;
+use super::{PuzzleInfo, Registration, Solution};
 use common::Answer;
 
 #[derive(Default)]
 pub struct Puzzle;
 
+pub(super) const REGISTRATION: Registration = Registration::new::<Puzzle>(PuzzleInfo {
+    year: 2023,
+    day: 1,
+    title: "Trebuchet?!",
+    has_visualization: true,
+    parameters: &[],
+    assumptions: &[],
+    exports: &[],
+});
+
 impl Solution for Puzzle {
     fn solve_a(&mut self, input: String) -> Result<Answer, String> {
         Answer::from(read_numbers(input)).into()
@@ -71,6 +81,10 @@ fn str_as_digit(input: &str) -> u32 {
 }
 
 #[cfg(test)]
+#[allow(
+    clippy::default_constructed_unit_structs,
+    clippy::items_after_test_module
+)]
 mod tests {
     use super::Puzzle;
     use super::Solution;
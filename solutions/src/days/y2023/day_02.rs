@@ -1,14 +1,27 @@
-use std::{ops::Add, str::FromStr};
+use std::ops::Add;
 
-use super::Solution;
-use common::Answer;
+use super::{PuzzleInfo, Registration, Solution};
+use common::{
+    parse::{self, Line, ParseError},
+    Answer,
+};
 
 #[derive(Default)]
 pub struct Puzzle;
 
+pub(super) const REGISTRATION: Registration = Registration::new::<Puzzle>(PuzzleInfo {
+    year: 2023,
+    day: 2,
+    title: "Cube Conundrum",
+    has_visualization: true,
+    parameters: &[],
+    assumptions: &[],
+    exports: &[],
+});
+
 impl Solution for Puzzle {
     fn solve_a(&mut self, input: String) -> Result<Answer, String> {
-        let games = parse_input(input);
+        let games = parse_input(input)?;
         Answer::from(
             games
                 .iter()
@@ -26,7 +39,7 @@ impl Solution for Puzzle {
     }
 
     fn solve_b(&mut self, input: String) -> Result<Answer, String> {
-        let games = parse_input(input);
+        let games = parse_input(input)?;
         Answer::from(games.iter().map(|g| g.set_power()).sum::<u32>()).into()
     }
 
@@ -36,7 +49,7 @@ impl Solution for Puzzle {
         input: String,
         _request: ui_support::DisplayRequest,
     ) -> Option<ui_support::DisplayResult> {
-        Some(build_shapes_for_ui(input).into())
+        build_shapes_for_ui(input).map(|s| s.into()).ok()
     }
 }
 
@@ -44,39 +57,38 @@ struct Game {
     id: u32,
     sets: Vec<Set>,
 }
-impl FromStr for Game {
-    type Err = String;
+impl TryFrom<Line<'_>> for Game {
+    type Error = ParseError;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.strip_prefix("Game ").unwrap();
-        let (id, sets) = s.split_once(": ").unwrap();
-        let id: u32 = id.parse().unwrap();
+    fn try_from(line: Line<'_>) -> Result<Self, Self::Error> {
+        let (id, sets) = line.strip_prefix("Game ")?.split_once(":")?;
+        let id: u32 = id.parse()?;
         let sets = sets
-            .split("; ")
+            .split(";")
             .map(|set| {
-                set.split(", ")
+                set.split(",")
                     .map(|item| {
-                        let (count, color) = item.split_once(' ').unwrap();
-                        let count: u32 = count.parse().unwrap();
-                        match color {
-                            "red" => Set {
+                        let (count, color) = item.split_once(" ")?;
+                        let count: u32 = count.parse()?;
+                        match color.text {
+                            "red" => Ok(Set {
                                 red: count,
                                 ..Default::default()
-                            },
-                            "green" => Set {
+                            }),
+                            "green" => Ok(Set {
                                 green: count,
                                 ..Default::default()
-                            },
-                            "blue" => Set {
+                            }),
+                            "blue" => Ok(Set {
                                 blue: count,
                                 ..Default::default()
-                            },
-                            _ => panic!("Unknown color {}", color),
+                            }),
+                            _ => Err(color.error(format!("Unknown color {}", color.text))),
                         }
                     })
-                    .fold(Set::default(), |acc, f| acc + f)
+                    .try_fold(Set::default(), |acc, f| Ok(acc + f?))
             })
-            .collect();
+            .collect::<Result<_, ParseError>>()?;
         Ok(Self { id, sets })
     }
 }
@@ -121,14 +133,15 @@ impl Add for Set {
     }
 }
 
-fn parse_input(input: String) -> Vec<Game> {
-    input
-        .lines()
-        .map(|line| line.parse::<Game>().unwrap())
-        .collect()
+fn parse_input(input: String) -> Result<Vec<Game>, ParseError> {
+    parse::lines(&input).map(Game::try_from).collect()
 }
 
 #[cfg(test)]
+#[allow(
+    clippy::default_constructed_unit_structs,
+    clippy::items_after_test_module
+)]
 mod tests {
     use super::Puzzle;
     use super::Solution;
@@ -160,7 +173,7 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
 }
 
 #[cfg(feature = "ui")]
-fn build_shapes_for_ui(input: String) -> Vec<ui_support::DisplayData> {
+fn build_shapes_for_ui(input: String) -> Result<Vec<ui_support::DisplayData>, ParseError> {
     use egui::{
         epaint::{Color32, RectShape, Shape},
         Pos2, Rect, Rounding, Stroke,
@@ -174,8 +187,8 @@ fn build_shapes_for_ui(input: String) -> Vec<ui_support::DisplayData> {
         })
     };
 
-    let games = parse_input(input);
-    games
+    let games = parse_input(input)?;
+    Ok(games
         .into_iter()
         .enumerate()
         .flat_map(|(y, game)| {
@@ -217,5 +230,5 @@ fn build_shapes_for_ui(input: String) -> Vec<ui_support::DisplayData> {
                 .collect::<Vec<Shape>>()
         })
         .map(|s| s.into())
-        .collect()
+        .collect())
 }
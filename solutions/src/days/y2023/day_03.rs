@@ -0,0 +1,219 @@
+#[cfg(feature = "performance")]
+use ahash::AHashMap as HashMap;
+#[cfg(not(feature = "performance"))]
+use std::collections::HashMap;
+
+use super::{PuzzleInfo, Registration, Solution};
+use common::{Answer, Grid, GridPos};
+
+#[derive(Default)]
+pub struct Puzzle;
+
+pub(super) const REGISTRATION: Registration = Registration::new::<Puzzle>(PuzzleInfo {
+    year: 2023,
+    day: 3,
+    title: "Gear Ratios",
+    has_visualization: true,
+    parameters: &[],
+    assumptions: &[],
+    exports: &[],
+});
+
+impl Solution for Puzzle {
+    fn solve_a(&mut self, input: String) -> Result<Answer, String> {
+        let (grid, numbers) = parse_input(input)?;
+        let attached_numbers = get_attached_numbers(&grid, &numbers);
+        Answer::from(attached_numbers.map(|n| n.value).sum::<u32>()).into()
+    }
+
+    fn solve_b(&mut self, input: String) -> Result<Answer, String> {
+        let (grid, numbers) = parse_input(input)?;
+        let gears: Vec<u32> = get_numbers_with_gears(&grid, &numbers)
+            .map(|(_, v)| v.first().unwrap().value * v.get(1).unwrap().value)
+            .collect();
+        Answer::from(gears.iter().sum::<u32>()).into()
+    }
+
+    #[cfg(feature = "ui")]
+    fn get_shapes(
+        &mut self,
+        input: String,
+        _request: ui_support::DisplayRequest,
+    ) -> Option<ui_support::DisplayResult> {
+        build_shapes_for_ui(input).map(|s| s.into()).ok()
+    }
+}
+
+#[derive(Debug)]
+struct Number {
+    pos: GridPos,
+    value: u32,
+    digits: usize,
+}
+
+impl Number {
+    /// Cells around the number, including diagonals
+    fn neighbors<'a>(&self, grid: &'a Grid<char>) -> impl Iterator<Item = GridPos> + 'a {
+        let (x, y) = self.pos;
+        let digits = x..x + self.digits;
+        digits
+            .clone()
+            .flat_map(move |x| grid.neighbors8((x, y)))
+            .filter(move |(nx, ny)| *ny != y || !digits.contains(nx))
+    }
+}
+
+fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit()
+}
+
+fn parse_input(input: String) -> Result<(Grid<char>, Vec<Number>), String> {
+    let grid = Grid::parse(input.trim(), |c| c)?;
+    let mut numbers = vec![];
+    for (y, row) in grid.rows().enumerate() {
+        let mut x = 0;
+        while x < row.len() {
+            let digits = row[x..].iter().take_while(|c| c.is_ascii_digit()).count();
+            if digits == 0 {
+                x += 1;
+                continue;
+            }
+            let value = row[x..x + digits]
+                .iter()
+                .fold(0, |acc, c| acc * 10 + c.to_digit(10).unwrap());
+            numbers.push(Number {
+                pos: (x, y),
+                value,
+                digits,
+            });
+            x += digits;
+        }
+    }
+    Ok((grid, numbers))
+}
+
+fn get_attached_numbers<'a>(
+    grid: &'a Grid<char>,
+    numbers: &'a [Number],
+) -> impl Iterator<Item = &'a Number> {
+    numbers
+        .iter()
+        .filter(|n| n.neighbors(grid).any(|pos| is_symbol(grid[pos])))
+}
+
+fn get_numbers_with_gears<'a>(
+    grid: &'a Grid<char>,
+    numbers: &'a [Number],
+) -> impl Iterator<Item = (GridPos, Vec<&'a Number>)> {
+    numbers
+        .iter()
+        .filter_map(|n| {
+            n.neighbors(grid)
+                .find(|pos| grid[*pos] == '*')
+                .map(|pos| (pos, n))
+        })
+        .fold(
+            HashMap::new(),
+            |mut map: HashMap<GridPos, Vec<&Number>>, (pos, n)| {
+                map.entry(pos).or_default().push(n);
+                map
+            },
+        )
+        .into_iter()
+        .filter(|(_, v)| v.len() == 2)
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::default_constructed_unit_structs,
+    clippy::items_after_test_module
+)]
+mod tests {
+    use super::Puzzle;
+    use super::Solution;
+    use common::Answer;
+
+    const TEST_INPUT: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    #[tokio::test]
+    async fn part_a() {
+        let mut puzzle = Puzzle::default();
+        assert_eq!(
+            puzzle.solve_a(String::from(TEST_INPUT)),
+            Ok(Answer::from(4361))
+        )
+    }
+
+    #[tokio::test]
+    async fn part_b() {
+        let mut puzzle = Puzzle::default();
+        assert_eq!(
+            puzzle.solve_b(String::from(TEST_INPUT)),
+            Ok(Answer::from(467835))
+        )
+    }
+}
+
+#[cfg(feature = "ui")]
+fn build_shapes_for_ui(input: String) -> Result<Vec<ui_support::DisplayData>, String> {
+    use egui::epaint::*;
+
+    let mut shapes = vec![];
+    let (grid, numbers) = parse_input(input)?;
+    shapes.extend(grid.positions(|c| is_symbol(*c)).map(|pos| {
+        ui_support::DisplayData::text(
+            Pos2::new(pos.0 as f32, pos.1 as f32),
+            grid[pos].to_string(),
+            1.0,
+            Color32::YELLOW,
+        )
+    }));
+    shapes.extend(numbers.iter().map(|n| {
+        ui_support::DisplayData::text(
+            Pos2::new(n.pos.0 as f32, n.pos.1 as f32),
+            n.value.to_string(),
+            1.0,
+            Color32::BLUE,
+        )
+    }));
+    shapes.extend(get_attached_numbers(&grid, &numbers).map(|n| {
+        ui_support::DisplayData::text(
+            Pos2::new(n.pos.0 as f32, n.pos.1 as f32),
+            n.value.to_string(),
+            1.0,
+            Color32::GREEN,
+        )
+    }));
+    shapes.extend(
+        get_numbers_with_gears(&grid, &numbers).flat_map(|(gear, numbers)| {
+            let mut s: Vec<ui_support::DisplayData> = numbers
+                .iter()
+                .map(|n| {
+                    ui_support::DisplayData::text(
+                        Pos2::new(n.pos.0 as f32, n.pos.1 as f32),
+                        n.value.to_string(),
+                        1.0,
+                        Color32::RED,
+                    )
+                })
+                .collect();
+            s.push(ui_support::DisplayData::text(
+                Pos2::new(gear.0 as f32, gear.1 as f32),
+                "*".to_string(),
+                1.0,
+                Color32::RED,
+            ));
+            s
+        }),
+    );
+    Ok(shapes)
+}
@@ -1,11 +1,21 @@
 use std::str::FromStr;
 
-use super::Solution;
+use super::{PuzzleInfo, Registration, Solution};
 use common::Answer;
 
 #[derive(Default)]
 pub struct Puzzle;
 
+pub(super) const REGISTRATION: Registration = Registration::new::<Puzzle>(PuzzleInfo {
+    year: 2023,
+    day: 4,
+    title: "Scratchcards",
+    has_visualization: true,
+    parameters: &[],
+    assumptions: &[],
+    exports: &[],
+});
+
 impl Solution for Puzzle {
     fn solve_a(&mut self, input: String) -> Result<Answer, String> {
         let cards = input.lines().map(|l| l.parse::<Card>().unwrap());
@@ -71,6 +81,10 @@ impl Card {
 }
 
 #[cfg(test)]
+#[allow(
+    clippy::default_constructed_unit_structs,
+    clippy::items_after_test_module
+)]
 mod tests {
     use super::Puzzle;
     use super::Solution;
@@ -110,7 +124,6 @@ fn build_shapes_for_ui(input: String) -> Vec<ui_support::DisplayData> {
     let mut counts = cards.clone().map(|_| 1_u32).collect::<Vec<_>>();
     cards
         .enumerate()
-        .into_iter()
         .flat_map(|(y, card)| {
             let count_space = 8.0;
             let winning_numbers = card.winning_numbers();
@@ -123,7 +136,7 @@ fn build_shapes_for_ui(input: String) -> Vec<ui_support::DisplayData> {
                 })
                 .collect::<Vec<ui_support::DisplayData>>();
             let wins = shapes.len();
-            for y2 in (y + 1)..=y + (wins as usize) {
+            for y2 in (y + 1)..=y + wins {
                 counts[y2] += counts[y];
             }
             shapes.push(ui_support::DisplayData::text(
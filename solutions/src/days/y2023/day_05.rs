@@ -0,0 +1,215 @@
+use std::ops::Range;
+
+use super::{PuzzleInfo, Registration, Solution};
+use common::{
+    interval::{Interval, IntervalSet},
+    parse::{self, Block, Line, ParseError},
+    Answer,
+};
+
+#[derive(Default)]
+pub struct Puzzle;
+
+pub(super) const REGISTRATION: Registration = Registration::new::<Puzzle>(PuzzleInfo {
+    year: 2023,
+    day: 5,
+    title: "If You Give A Seed A Fertilizer",
+    has_visualization: false,
+    parameters: &[],
+    assumptions: &[],
+    exports: &[],
+});
+
+impl Solution for Puzzle {
+    fn solve_a(&mut self, input: String) -> Result<Answer, String> {
+        let (seeds, maps) = parse_input(input)?;
+        let locations = seeds.into_iter().map(|s| convert(s, &maps));
+        let min_loc: Option<usize> = locations.min();
+        Answer::from(min_loc).into()
+    }
+
+    fn solve_b(&mut self, input: String) -> Result<Answer, String> {
+        let (seeds, maps) = parse_input(input)?;
+        let seed_ranges = seeds
+            .chunks(2)
+            .map(|s| Interval::new(s[0], s[0] + s[1]))
+            .collect::<IntervalSet<usize>>();
+        let min_loc = convert_range(seed_ranges, &maps).min();
+        Answer::from(min_loc).into()
+    }
+
+    #[cfg(feature = "ui")]
+    fn get_shapes(
+        &mut self,
+        _input: String,
+        _request: ui_support::DisplayRequest,
+    ) -> Option<ui_support::DisplayResult> {
+        None
+    }
+}
+
+fn parse_input(input: String) -> Result<(Vec<usize>, Vec<ConversionMap>), ParseError> {
+    let blocks = parse::blocks(&input);
+    let (seeds, maps) = blocks
+        .split_first()
+        .ok_or_else(|| Line::new(1, "").error("Missing seeds"))?;
+    let seeds = seeds.first().strip_prefix("seeds:")?.words()?;
+    let maps = maps
+        .iter()
+        .map(|block| ConversionMap::try_from(*block))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((seeds, maps))
+}
+
+fn convert(value: usize, maps: &[ConversionMap]) -> usize {
+    let mut value = value;
+    for map in maps {
+        value = map.convert(value);
+    }
+    value
+}
+fn convert_range(value: IntervalSet<usize>, maps: &[ConversionMap]) -> IntervalSet<usize> {
+    maps.iter()
+        .fold(value, |value, map| map.convert_range(&value))
+}
+#[allow(dead_code)]
+fn reverse(value: usize, maps: &[ConversionMap]) -> usize {
+    let mut value = value;
+    for map in maps {
+        value = map.reverse(value);
+    }
+    value
+}
+
+#[derive(Debug)]
+struct ConversionMap {
+    #[allow(dead_code)]
+    name: String,
+    ranges: Vec<ConversionRange>,
+}
+impl ConversionMap {
+    fn convert(&self, value: usize) -> usize {
+        match self.ranges.iter().find(|r| r.from.contains(&value)) {
+            Some(range) => range.to.start + (value - range.from.start),
+            None => value,
+        }
+    }
+    fn convert_range(&self, value: &IntervalSet<usize>) -> IntervalSet<usize> {
+        let mut unmapped = value.clone();
+        let mut converted = IntervalSet::new();
+        for r in &self.ranges {
+            let from = IntervalSet::from(Interval::from(r.from.clone()));
+            for overlap in value.intersection(&from).intervals() {
+                converted.insert(Interval::new(
+                    r.to.start + (overlap.start - r.from.start),
+                    r.to.start + (overlap.end - r.from.start),
+                ));
+            }
+            unmapped = unmapped.difference(&from); // out of bounds, retain value
+        }
+        converted.union(&unmapped)
+    }
+    #[allow(dead_code)]
+    fn reverse(&self, value: usize) -> usize {
+        match self.ranges.iter().find(|r| r.to.contains(&value)) {
+            Some(range) => range.from.start + (value - range.to.start),
+            None => value,
+        }
+    }
+}
+impl TryFrom<Block<'_>> for ConversionMap {
+    type Error = ParseError;
+
+    fn try_from(block: Block<'_>) -> Result<Self, Self::Error> {
+        let mut ranges = block
+            .lines()
+            .skip(1)
+            .map(ConversionRange::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        ranges.sort_by_key(|a| a.from.start);
+        Ok(Self {
+            name: block.first().text.into(),
+            ranges,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct ConversionRange {
+    from: Range<usize>,
+    to: Range<usize>,
+}
+impl TryFrom<Line<'_>> for ConversionRange {
+    type Error = ParseError;
+
+    fn try_from(line: Line<'_>) -> Result<Self, Self::Error> {
+        let values: Vec<usize> = line.words()?;
+        let [to, from, length] = values[..] else {
+            return Err(line.error("Expected 3 values"));
+        };
+        Ok(Self {
+            from: from..from + length,
+            to: to..to + length,
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::default_constructed_unit_structs)]
+mod tests {
+    use super::Puzzle;
+    use super::Solution;
+    use common::Answer;
+
+    const TEST_INPUT: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    #[tokio::test]
+    async fn part_a() {
+        let mut puzzle = Puzzle::default();
+        assert_eq!(
+            puzzle.solve_a(String::from(TEST_INPUT)),
+            Ok(Answer::from(35))
+        )
+    }
+
+    #[tokio::test]
+    async fn part_b() {
+        let mut puzzle = Puzzle::default();
+        assert_eq!(
+            puzzle.solve_b(String::from(TEST_INPUT)),
+            Ok(Answer::from(46))
+        )
+    }
+}
@@ -0,0 +1,125 @@
+use super::{PuzzleInfo, Registration, Solution};
+use common::{
+    parse::{self, Line, ParseError},
+    Answer,
+};
+
+#[derive(Default)]
+pub struct Puzzle;
+
+pub(super) const REGISTRATION: Registration = Registration::new::<Puzzle>(PuzzleInfo {
+    year: 2023,
+    day: 6,
+    title: "Wait For It",
+    has_visualization: false,
+    parameters: &[],
+    assumptions: &[],
+    exports: &[],
+});
+
+impl Solution for Puzzle {
+    fn solve_a(&mut self, input: String) -> Result<Answer, String> {
+        let input = read_input(input)?;
+        Answer::from(
+            input
+                .into_iter()
+                .map(find_winning_strategies)
+                .product::<usize>(),
+        )
+        .into()
+    }
+
+    fn solve_b(&mut self, input: String) -> Result<Answer, String> {
+        let input = read_input_with_fixed_kerning(input)?;
+        Answer::from(
+            input
+                .into_iter()
+                .map(find_winning_strategies)
+                .product::<usize>(),
+        )
+        .into()
+    }
+
+    #[cfg(feature = "ui")]
+    fn get_shapes(
+        &mut self,
+        _input: String,
+        _request: ui_support::DisplayRequest,
+    ) -> Option<ui_support::DisplayResult> {
+        None
+    }
+}
+
+fn read_input(input: String) -> Result<Vec<(usize, usize)>, ParseError> {
+    let (times, distances) = read_lines(&input)?;
+    let times: Vec<usize> = times.words()?;
+    let distances: Vec<usize> = distances.words()?;
+    Ok(times.into_iter().zip(distances).collect::<Vec<_>>())
+}
+
+fn read_input_with_fixed_kerning(input: String) -> Result<Vec<(usize, usize)>, ParseError> {
+    let (times, distances) = read_lines(&input)?;
+    let time = times.text.replace(' ', "");
+    let distance = distances.text.replace(' ', "");
+    Ok(vec![(
+        Line {
+            text: &time,
+            ..times
+        }
+        .parse()?,
+        Line {
+            text: &distance,
+            ..distances
+        }
+        .parse()?,
+    )])
+}
+
+/// The values of the `Time:` and `Distance:` lines
+fn read_lines(input: &str) -> Result<(Line<'_>, Line<'_>), ParseError> {
+    let lines = parse::lines(input).collect::<Vec<_>>();
+    let values = |index: usize, name: &str| match lines.get(index) {
+        Some(line) => line.strip_prefix(name),
+        None => Err(Line::new(index + 1, "").error(format!("Missing '{name}' line"))),
+    };
+    Ok((values(0, "Time:")?, values(1, "Distance:")?))
+}
+
+fn find_winning_strategies(game: (usize, usize)) -> usize {
+    (0..game.0)
+        .filter(|hold_time| {
+            let speed = hold_time;
+            let remaining_time = game.0 - hold_time;
+            speed * remaining_time > game.1
+        })
+        .count()
+}
+
+#[cfg(test)]
+#[allow(clippy::default_constructed_unit_structs)]
+mod tests {
+    use super::Puzzle;
+    use super::Solution;
+    use common::Answer;
+
+    const TEST_INPUT: &str = "Time:      7  15   30
+Distance:  9  40  200";
+
+    #[tokio::test]
+    async fn part_a() {
+        let mut puzzle = Puzzle::default();
+        assert_eq!(
+            puzzle.solve_a(String::from(TEST_INPUT)),
+            Ok(Answer::from(288))
+        )
+    }
+
+    #[tokio::test]
+    async fn part_b() {
+        let mut puzzle = Puzzle::default();
+        assert_eq!(
+            puzzle.solve_b(String::from(TEST_INPUT)),
+            Ok(Answer::from(71503))
+        )
+    }
+}
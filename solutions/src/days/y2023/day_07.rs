@@ -4,12 +4,22 @@ use ahash::AHashMap as HashMap;
 use std::collections::HashMap;
 use std::str::FromStr;
 
-use super::Solution;
+use super::{PuzzleInfo, Registration, Solution};
 use common::Answer;
 
 #[derive(Default)]
 pub struct Puzzle;
 
+pub(super) const REGISTRATION: Registration = Registration::new::<Puzzle>(PuzzleInfo {
+    year: 2023,
+    day: 7,
+    title: "Camel Cards",
+    has_visualization: false,
+    parameters: &[],
+    assumptions: &[],
+    exports: &[],
+});
+
 impl Solution for Puzzle {
     fn solve_a(&mut self, input: String) -> Result<Answer, String> {
         let mut hands = parse_input_a(input);
@@ -242,6 +252,7 @@ enum HandType {
 }
 
 #[cfg(test)]
+#[allow(clippy::default_constructed_unit_structs)]
 mod tests {
     use super::Puzzle;
     use super::Solution;
@@ -4,12 +4,22 @@ use ahash::AHashMap as HashMap;
 use std::collections::HashMap;
 use std::str::FromStr;
 
-use super::Solution;
-use common::Answer;
+use super::{PuzzleInfo, Registration, Solution};
+use common::{cycle, math, Answer};
 
 #[derive(Default)]
 pub struct Puzzle;
 
+pub(super) const REGISTRATION: Registration = Registration::new::<Puzzle>(PuzzleInfo {
+    year: 2023,
+    day: 8,
+    title: "Haunted Wasteland",
+    has_visualization: false,
+    parameters: &[],
+    assumptions: &[],
+    exports: &[],
+});
+
 impl Solution for Puzzle {
     fn solve_a(&mut self, input: String) -> Result<Answer, String> {
         let (instructions, nodes) = parse_input(input);
@@ -23,7 +33,7 @@ impl Solution for Puzzle {
             .filter(|(_, n)| n.is_start)
             .map(|(_, node)| node)
             .collect();
-        Answer::from(calculate_smart_ghost_steps(&instructions, &nodes, start)).into()
+        Answer::from(calculate_smart_ghost_steps(&instructions, &nodes, start)?).into()
     }
 
     #[cfg(feature = "ui")]
@@ -47,7 +57,7 @@ fn parse_input(input: String) -> (Vec<Direction>, HashMap<String, Node>) {
     (instructions, nodes)
 }
 
-fn calculate_steps(instructions: &Vec<Direction>, nodes: &HashMap<String, Node>) -> usize {
+fn calculate_steps(instructions: &[Direction], nodes: &HashMap<String, Node>) -> usize {
     let mut location = "AAA";
     let mut steps = 0_usize;
     loop {
@@ -64,65 +74,91 @@ fn calculate_steps(instructions: &Vec<Direction>, nodes: &HashMap<String, Node>)
     }
 }
 
-fn calculate_ghost_steps<'a>(
-    instructions: &'a Vec<Direction>,
-    nodes: &'a HashMap<String, Node>,
-    mut location: &'a Node,
-) -> usize {
-    let mut steps = 0_usize;
-    loop {
-        if location.is_end {
-            return steps;
+/// Steps at which a ghost is on a Z node; the hits within its cycle repeat every `period` steps
+struct GhostHits {
+    cycle_start: usize,
+    period: usize,
+    before_cycle: Vec<usize>,
+    in_cycle: Vec<usize>,
+}
+
+impl GhostHits {
+    /// Follows the ghost until it is back at the same node and instruction
+    fn new(instructions: &[Direction], nodes: &HashMap<String, Node>, start: &Node) -> Self {
+        let states = cycle::find_hashed((start.name.as_str(), 0), |&(name, index)| {
+            let node = nodes.get(name).unwrap();
+            let next = match instructions[index] {
+                Direction::Left => &node.left,
+                Direction::Right => &node.right,
+            };
+            (next.as_str(), (index + 1) % instructions.len())
+        });
+        let (before_cycle, in_cycle) = states
+            .states
+            .iter()
+            .enumerate()
+            .filter(|(_, (name, _))| nodes.get(*name).unwrap().is_end)
+            .map(|(step, _)| step)
+            .partition(|step| *step < states.cycle.start);
+        Self {
+            cycle_start: states.cycle.start,
+            period: states.cycle.length,
+            before_cycle,
+            in_cycle,
+        }
+    }
+
+    fn hits_at(&self, step: usize) -> bool {
+        if step < self.cycle_start {
+            self.before_cycle.contains(&step)
+        } else {
+            let step = self.cycle_start + (step - self.cycle_start) % self.period;
+            self.in_cycle.contains(&step)
         }
-        let direction = instructions.get(steps % instructions.len()).unwrap();
-        location = match direction {
-            Direction::Left => nodes.get(&location.left).unwrap(),
-            Direction::Right => nodes.get(&location.right).unwrap(),
-        };
-        steps += 1;
     }
 }
 
+/// First step at which all ghosts are on a Z node at the same time. Before every ghost is in
+/// its cycle the steps are checked one by one, after that every combination of hits within the
+/// cycles gives a set of congruences to solve.
 fn calculate_smart_ghost_steps(
-    instructions: &Vec<Direction>,
+    instructions: &[Direction],
     nodes: &HashMap<String, Node>,
     location: Vec<&Node>,
-) -> usize {
-    location
-        .iter()
-        .map(|n| calculate_ghost_steps(instructions, nodes, n))
-        .fold(0, |prev, current| {
-            if prev == 0 {
-                current
-            } else {
-                get_lcm((prev, current))
-            }
-        })
-}
-
-/// Calculate the least common multiple of two numbers.
-/// Based of https://en.wikipedia.org/wiki/Least_common_multiple#Using_the_greatest_common_divisor
-pub fn get_lcm((left, right): (usize, usize)) -> usize {
-    let gcd = get_gcd((left, right));
-    left * right / gcd
-}
-
-/// Calculate the greatest common divisor of two numbers.
-/// Based of https://en.wikipedia.org/wiki/Euclidean_algorithm
-pub fn get_gcd((mut left, mut right): (usize, usize)) -> usize {
-    if right > left {
-        std::mem::swap(&mut left, &mut right);
+) -> Result<usize, String> {
+    let ghosts: Vec<GhostHits> = location
+        .into_iter()
+        .map(|n| GhostHits::new(instructions, nodes, n))
+        .collect();
+    let settled = ghosts.iter().map(|g| g.cycle_start).max().unwrap_or(0);
+    if let Some(step) = (0..settled).find(|step| ghosts.iter().all(|g| g.hits_at(*step))) {
+        return Ok(step);
     }
-    loop {
-        if right == 0 {
-            return left;
-        }
-        left %= right;
-        if left == 0 {
-            return right;
-        }
-        right %= left;
+
+    let mut congruences = vec![(0_i128, 1_i128)];
+    for ghost in &ghosts {
+        let period = ghost.period as i128;
+        congruences = congruences
+            .into_iter()
+            .flat_map(|congruence| {
+                ghost.in_cycle.iter().filter_map(move |step| {
+                    math::crt([congruence, (*step as i128 % period, period)])
+                })
+            })
+            .collect();
+        congruences.sort_unstable();
+        congruences.dedup();
     }
+    congruences
+        .into_iter()
+        .map(|(residue, modulus)| {
+            let behind = (settled as i128 - residue).max(0);
+            (residue + (behind + modulus - 1) / modulus * modulus) as usize
+        })
+        .min()
+        .ok_or(String::from(
+            "The ghosts are never on Z nodes at the same time",
+        ))
 }
 
 #[derive(Debug)]
@@ -171,6 +207,7 @@ impl FromStr for Node {
 }
 
 #[cfg(test)]
+#[allow(clippy::default_constructed_unit_structs)]
 mod tests {
     use super::Puzzle;
     use super::Solution;
@@ -216,12 +253,30 @@ XXX = (XXX, XXX)";
         );
     }
 
+    /// The first ghost hits Z after 1, 4, 7, ... steps, the second after 2, 4, 6, ...
+    const TEST_INPUT_UNALIGNED: &str = "L
+
+11A = (11Z, 11Z)
+11Z = (11B, 11B)
+11B = (11C, 11C)
+11C = (11Z, 11Z)
+22A = (22B, 22B)
+22B = (22Z, 22Z)
+22Z = (22B, 22B)";
+
     #[tokio::test]
     async fn part_b() {
         let mut puzzle = Puzzle::default();
         assert_eq!(
             puzzle.solve_b(String::from(TEST_INPUT3)),
             Ok(Answer::from(6))
-        )
+        );
+        assert_eq!(
+            puzzle.solve_b(String::from(TEST_INPUT_UNALIGNED)),
+            Ok(Answer::from(4))
+        );
+        assert!(puzzle
+            .solve_b(TEST_INPUT_UNALIGNED.replace("11B = (11C, 11C)", "11B = (11Z, 11Z)"))
+            .is_err());
     }
 }
@@ -1,25 +1,35 @@
-use super::Solution;
-use common::Answer;
+use super::{PuzzleInfo, Registration, Solution};
+use common::{math, Answer};
 
 #[derive(Default)]
 pub struct Puzzle;
 
+pub(super) const REGISTRATION: Registration = Registration::new::<Puzzle>(PuzzleInfo {
+    year: 2023,
+    day: 9,
+    title: "Mirage Maintenance",
+    has_visualization: true,
+    parameters: &[],
+    assumptions: &[],
+    exports: &[],
+});
+
 impl Solution for Puzzle {
     fn solve_a(&mut self, input: String) -> Result<Answer, String> {
-        let history = parse_input(input);
+        let history = parse_input(input)?;
         let total = history
             .into_iter()
-            .map(|v| v.last().unwrap() + extrapolate(&v).1)
-            .sum::<isize>();
+            .map(|v| extrapolate(&v, v.len() as isize))
+            .sum::<Result<isize, String>>()?;
         Answer::from(total).into()
     }
 
     fn solve_b(&mut self, input: String) -> Result<Answer, String> {
-        let history = parse_input(input);
+        let history = parse_input(input)?;
         let total = history
             .into_iter()
-            .map(|v: Vec<isize>| v.first().unwrap() - extrapolate(&v).0)
-            .sum::<isize>();
+            .map(|v| extrapolate(&v, -1))
+            .sum::<Result<isize, String>>()?;
         Answer::from(total).into()
     }
 
@@ -29,38 +39,48 @@ impl Solution for Puzzle {
         input: String,
         _request: ui_support::DisplayRequest,
     ) -> Option<ui_support::DisplayResult> {
-        Some(build_shapes_for_ui(input).into())
+        build_shapes_for_ui(input).map(|s| s.into()).ok()
     }
 }
 
-fn parse_input(input: String) -> Vec<Vec<isize>> {
+fn parse_input(input: String) -> Result<Vec<Vec<isize>>, String> {
     input
         .lines()
         .map(|line| {
             line.split_ascii_whitespace()
-                .map(|d| d.parse::<isize>().unwrap())
-                .collect::<Vec<isize>>()
+                .map(|d| {
+                    d.parse::<isize>()
+                        .map_err(|e| format!("Invalid value {}: {}", d, e))
+                })
+                .collect::<Result<Vec<isize>, String>>()
         })
         .collect()
 }
 
-fn extrapolate(history: &[isize]) -> (isize, isize) {
-    if history.iter().all(|n| n == &0_isize) {
-        return (0, 0);
-    }
-    let deltas: Vec<isize> = deltas(history);
-    let extrapolated = extrapolate(&deltas);
-    (
-        deltas.first().unwrap() - extrapolated.0,
-        deltas.last().unwrap() + extrapolated.1,
-    )
+/// Extrapolates the history `x` steps from its start, through the lowest degree polynomial
+fn extrapolate(history: &[isize], x: isize) -> Result<isize, String> {
+    let samples = history.iter().map(|n| *n as i128).collect::<Vec<_>>();
+    math::newton_extrapolate(&samples, x as i128)
+        .to_integer()
+        .and_then(|n| isize::try_from(n).ok())
+        .ok_or_else(|| {
+            format!(
+                "History {:?} does not extrapolate to an integer at {}",
+                history, x
+            )
+        })
 }
 
+#[cfg(feature = "ui")]
 fn deltas(history: &[isize]) -> Vec<isize> {
     history.windows(2).map(|w| w[1] - w[0]).collect()
 }
 
 #[cfg(test)]
+#[allow(
+    clippy::default_constructed_unit_structs,
+    clippy::items_after_test_module
+)]
 mod tests {
     use super::Puzzle;
     use super::Solution;
@@ -76,7 +96,12 @@ mod tests {
         assert_eq!(
             puzzle.solve_a(String::from(TEST_INPUT)),
             Ok(Answer::from(114))
-        )
+        );
+        // the next value does not fit in an isize
+        assert!(puzzle
+            .solve_a(String::from("0 0 9223372036854775807"))
+            .is_err());
+        assert!(puzzle.solve_a(String::from("0 3 six 9")).is_err());
     }
 
     #[tokio::test]
@@ -90,15 +115,15 @@ mod tests {
 }
 
 #[cfg(feature = "ui")]
-fn build_shapes_for_ui(input: String) -> Vec<ui_support::DisplayData> {
-    let history = parse_input(input);
+fn build_shapes_for_ui(input: String) -> Result<Vec<ui_support::DisplayData>, String> {
+    let history = parse_input(input)?;
     let list_as_str = |list: &Vec<isize>| -> String {
         list.iter()
             .map(|n| n.to_string())
             .collect::<Vec<String>>()
             .join(" ")
     };
-    history
+    Ok(history
         .into_iter()
         .flat_map(|mut v| {
             let start_line = list_as_str(&v);
@@ -118,7 +143,7 @@ fn build_shapes_for_ui(input: String) -> Vec<ui_support::DisplayData> {
                     delta_line
                 )));
             }
-            return log_lines;
+            log_lines
         })
-        .collect::<Vec<ui_support::DisplayData>>()
+        .collect::<Vec<ui_support::DisplayData>>())
 }
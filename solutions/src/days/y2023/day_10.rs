@@ -4,24 +4,33 @@ use ahash::AHashMap as HashMap;
 use std::collections::HashMap;
 use std::str::FromStr;
 
-use super::Solution;
-use common::Answer;
+use super::{PuzzleInfo, Registration, Solution};
+use common::{Answer, Grid};
 
 #[derive(Default)]
 pub struct Puzzle;
 
 type Coord = (usize, usize);
-type Grid = Vec<Vec<Cell>>;
+
+pub(super) const REGISTRATION: Registration = Registration::new::<Puzzle>(PuzzleInfo {
+    year: 2023,
+    day: 10,
+    title: "Pipe Maze",
+    has_visualization: true,
+    parameters: &[],
+    assumptions: &[],
+    exports: &[],
+});
 
 impl Solution for Puzzle {
     fn solve_a(&mut self, input: String) -> Result<Answer, String> {
-        let grid = parse_input(input);
+        let grid = parse_input(&input)?;
         let path = find_path(&grid);
         Answer::from(path.len() / 2).into()
     }
 
     fn solve_b(&mut self, input: String) -> Result<Answer, String> {
-        let grid = parse_input(input);
+        let grid = parse_input(&input)?;
         let path = find_path(&grid);
         let enclosed = find_enclosed_ground_cells(&grid, &path);
         Answer::from(enclosed.len()).into()
@@ -33,38 +42,21 @@ impl Solution for Puzzle {
         input: String,
         _request: ui_support::DisplayRequest,
     ) -> Option<ui_support::DisplayResult> {
-        Some(build_shapes_for_ui(input).into())
+        build_shapes_for_ui(input).map(|s| s.into()).ok()
     }
 }
 
-fn parse_input(input: String) -> Grid {
-    input
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| Cell::from_str(&c.to_string()).unwrap())
-                .collect()
-        })
-        .collect()
+fn parse_input(input: &str) -> Result<Grid<Cell>, String> {
+    Grid::try_parse(input, |c| Cell::from_str(&c.to_string()))
 }
 
-fn find_path(grid: &Grid) -> Vec<Coord> {
-    let start_pos = grid
-        .iter()
-        .enumerate()
-        .find_map(|(y, row)| {
-            row.iter().enumerate().find_map(|(x, cell)| match cell {
-                Cell::Start => Some((x, y)),
-                _ => None,
-            })
-        })
-        .expect("Start point");
-    let start = start_pos;
+fn find_path(grid: &Grid<Cell>) -> Vec<Coord> {
+    let start = grid.find(|c| *c == Cell::Start).expect("Start point");
     let mut prev = start;
     let mut current = Cell::find_first_step(grid, start);
     let mut path = vec![current];
     while current != start {
-        let cell = &grid[current.1][current.0];
+        let cell = &grid[current];
         let next = cell.make_step(prev, current);
         prev = current;
         current = next;
@@ -73,17 +65,17 @@ fn find_path(grid: &Grid) -> Vec<Coord> {
     path
 }
 
-fn find_enclosed_ground_cells(grid: &Grid, path: &[Coord]) -> Vec<Coord> {
+fn find_enclosed_ground_cells(grid: &Grid<Cell>, path: &[Coord]) -> Vec<Coord> {
     let path_map = HashMap::from_iter(path.iter().map(|c| {
-        let cell = &grid[c.1][c.0];
+        let cell = &grid[*c];
         if cell == &Cell::Start {
             (*c, Cell::replace_start(grid, *c))
         } else {
-            (*c, grid[c.1][c.0].clone())
+            (*c, cell.clone())
         }
     }));
     let enclosed_cells = grid
-        .iter()
+        .rows()
         .enumerate()
         .flat_map(|(y, row)| get_enclosed(y, row, &path_map))
         .collect::<Vec<Coord>>();
@@ -137,7 +129,7 @@ enum Cell {
 }
 
 impl Cell {
-    fn replace_start(grid: &Grid, (s_x, s_y): Coord) -> Cell {
+    fn replace_start(grid: &Grid<Cell>, (s_x, s_y): Coord) -> Cell {
         let mut options = vec![
             Cell::Horizontal,
             Cell::Vertical,
@@ -147,28 +139,28 @@ impl Cell {
             Cell::BendSW,
         ];
         if s_x > 0 {
-            match &grid[s_y][s_x - 1] {
+            match &grid[(s_x - 1, s_y)] {
                 Cell::Horizontal | Cell::BendNE | Cell::BendSE => options
                     .retain(|c| *c == Cell::Horizontal || *c == Cell::BendNW || *c == Cell::BendSW),
                 _ => (),
             };
         }
         if s_y > 0 {
-            match &grid[s_y - 1][s_x] {
+            match &grid[(s_x, s_y - 1)] {
                 Cell::Vertical | Cell::BendSW | Cell::BendSE => options
                     .retain(|c| *c == Cell::Vertical || *c == Cell::BendNW || *c == Cell::BendNE),
                 _ => (),
             };
         }
-        if s_y < grid.len() - 1 {
-            match &grid[s_y + 1][s_x] {
+        if s_y < grid.height() - 1 {
+            match &grid[(s_x, s_y + 1)] {
                 Cell::Vertical | Cell::BendNW | Cell::BendNE => options
                     .retain(|c| *c == Cell::Vertical || *c == Cell::BendSW || *c == Cell::BendSE),
                 _ => (),
             };
         }
-        if s_x < grid[0].len() - 1 {
-            match &grid[s_y][s_x + 1] {
+        if s_x < grid.width() - 1 {
+            match &grid[(s_x + 1, s_y)] {
                 Cell::Horizontal | Cell::BendNW | Cell::BendSW => options
                     .retain(|c| *c == Cell::Horizontal || *c == Cell::BendNE || *c == Cell::BendSE),
                 _ => (),
@@ -180,7 +172,7 @@ impl Cell {
         );
         options[0].to_owned()
     }
-    fn find_first_step(grid: &Grid, (s_x, s_y): Coord) -> Coord {
+    fn find_first_step(grid: &Grid<Cell>, (s_x, s_y): Coord) -> Coord {
         let start_cell = Self::replace_start(grid, (s_x, s_y));
         match start_cell {
             Cell::Horizontal => (s_x + 1, s_y),
@@ -264,6 +256,10 @@ impl FromStr for Cell {
 }
 
 #[cfg(test)]
+#[allow(
+    clippy::default_constructed_unit_structs,
+    clippy::items_after_test_module
+)]
 mod tests {
     use super::Puzzle;
     use super::Solution;
@@ -330,17 +326,17 @@ L7JLJL-JLJLJL--JLJ.L";
 }
 
 #[cfg(feature = "ui")]
-fn build_shapes_for_ui(input: String) -> Vec<ui_support::DisplayData> {
+fn build_shapes_for_ui(input: String) -> Result<Vec<ui_support::DisplayData>, String> {
     use egui::epaint::{CircleShape, Color32, PathShape, Shape, Stroke};
 
-    let grid = parse_input(input);
+    let grid = parse_input(&input)?;
     let path = find_path(&grid);
     let enclosed = find_enclosed_ground_cells(&grid, &path);
     let no_path_color = Color32::from_rgb(255, 0, 0);
     let path_color = Color32::from_rgb(0, 255, 0);
     let enclosed_color = Color32::from_rgb(0, 255, 255);
 
-    ui_support::render_grid(&grid, move |cell, pos| {
+    Ok(ui_support::render_grid(&grid, move |cell, pos| {
         let (x, y) = ui_support::pos_into_coord(pos);
         let stroke_color = if path.contains(&(x, y)) {
             path_color
@@ -441,7 +437,7 @@ fn build_shapes_for_ui(input: String) -> Vec<ui_support::DisplayData> {
                 .into(),
             ),
         }]
-    })
+    }))
 
     // grid.into_iter()
     //     .enumerate()
@@ -1,4 +1,4 @@
-use super::Solution;
+use super::{Parameter, ParameterField, PuzzleInfo, Registration, Solution};
 use common::Answer;
 
 pub struct Puzzle {
@@ -15,6 +15,19 @@ impl Default for Puzzle {
 
 type Coord = (usize, usize);
 
+pub(super) const REGISTRATION: Registration = Registration::new::<Puzzle>(PuzzleInfo {
+    year: 2023,
+    day: 11,
+    title: "Cosmic Expansion",
+    has_visualization: false,
+    parameters: &[Parameter {
+        name: "part_b_grow_size",
+        description: "Factor by which empty rows and columns grow in part B",
+    }],
+    assumptions: &[],
+    exports: &[],
+});
+
 impl Solution for Puzzle {
     fn solve_a(&mut self, input: String) -> Result<Answer, String> {
         let coords = parse_input(input, 2);
@@ -28,6 +41,13 @@ impl Solution for Puzzle {
         Answer::from(pairs.iter().map(get_distance).sum::<usize>()).into()
     }
 
+    fn parameters(&mut self) -> Vec<ParameterField<'_>> {
+        vec![ParameterField::new_unsigned(
+            "part_b_grow_size",
+            &mut self.part_b_grow_size,
+        )]
+    }
+
     #[cfg(feature = "ui")]
     fn get_shapes(
         &mut self,
@@ -121,8 +141,9 @@ mod tests {
 
     #[tokio::test]
     async fn part_b() {
-        let mut puzzle = Puzzle::default();
-        puzzle.part_b_grow_size = 10;
+        let mut puzzle = Puzzle {
+            part_b_grow_size: 10,
+        };
         assert_eq!(
             puzzle.solve_b(String::from(TEST_INPUT)),
             Ok(Answer::from(1030))
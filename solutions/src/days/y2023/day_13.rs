@@ -1,16 +1,26 @@
-use super::Solution;
-use common::Answer;
+use super::{PuzzleInfo, Registration, Solution};
+use common::{Answer, Grid};
 
 #[derive(Default)]
 pub struct Puzzle;
 
+pub(super) const REGISTRATION: Registration = Registration::new::<Puzzle>(PuzzleInfo {
+    year: 2023,
+    day: 13,
+    title: "Point of Incidence",
+    has_visualization: true,
+    parameters: &[],
+    assumptions: &[],
+    exports: &[],
+});
+
 impl Solution for Puzzle {
     fn solve_a(&mut self, input: String) -> Result<Answer, String> {
-        Answer::from(parse_input(input, false)).into()
+        parse_input(input, false).map(Answer::from)
     }
 
     fn solve_b(&mut self, input: String) -> Result<Answer, String> {
-        Answer::from(parse_input(input, true)).into()
+        parse_input(input, true).map(Answer::from)
     }
 
     #[cfg(feature = "ui")]
@@ -19,52 +29,36 @@ impl Solution for Puzzle {
         input: String,
         request: ui_support::DisplayRequest,
     ) -> Option<ui_support::DisplayResult> {
-        Some(build_shapes_for_ui(input, request))
+        build_shapes_for_ui(input, request).ok()
     }
 }
 
-fn parse_input(input: String, part_b: bool) -> usize {
+fn parse_input(input: String, part_b: bool) -> Result<usize, String> {
     input
         .split("\n\n")
-        .map(|s| parse_section(s, part_b))
-        .sum::<usize>()
+        .map(|s| Ok(find_mirror(&parse_section(s)?, part_b)))
+        .sum()
+}
+
+/// Reads a section as grid, where rocks are `true`
+fn parse_section(input: &str) -> Result<Grid<bool>, String> {
+    Grid::parse(input, |c| c == '#')
 }
 
-fn parse_section(input: &str, part_b: bool) -> usize {
-    let base = read_as_number_list(input);
-    if let Some(mirror) = find_mirror_slice(base, part_b) {
+fn find_mirror(grid: &Grid<bool>, part_b: bool) -> usize {
+    if let Some(mirror) = find_mirror_slice(read_as_number_list(grid), part_b) {
         return mirror * 100;
     }
-    let flipped = read_as_number_list(&transpose_input(input));
-    find_mirror_slice(flipped, part_b).expect("No mirror found")
+    find_mirror_slice(read_as_number_list(&grid.transpose()), part_b).expect("No mirror found")
 }
 
-/// Returns a list of numbers where each number represents a row of the input
-fn read_as_number_list(input: &str) -> Vec<u32> {
-    input
-        .lines()
-        .map(|l| {
-            u32::from_str_radix(&l.to_string().replace('#', "1").replace('.', "0"), 2).unwrap()
-        })
+/// Returns a list of numbers where each number represents a row of the grid
+fn read_as_number_list(grid: &Grid<bool>) -> Vec<u32> {
+    grid.rows()
+        .map(|row| row.iter().fold(0, |acc, rock| acc << 1 | *rock as u32))
         .collect()
 }
 
-/// Transposes the input, so that the first row becomes the first column and so on
-fn transpose_input(input: &str) -> String {
-    let mut output = String::new();
-    let map = input
-        .lines()
-        .map(|l| l.chars().collect())
-        .collect::<Vec<Vec<char>>>();
-    for x in 0..map[0].len() {
-        for row in &map {
-            output.push(row[x]);
-        }
-        output.push('\n');
-    }
-    output
-}
-
 /// Returns the index of the mirror slice if found
 /// For part b, the mirror slice must have exactly one bit difference
 fn find_mirror_slice(values: Vec<u32>, part_b: bool) -> Option<usize> {
@@ -104,6 +98,10 @@ fn get_number_of_different_bits(a: u32, b: u32) -> u32 {
 }
 
 #[cfg(test)]
+#[allow(
+    clippy::default_constructed_unit_structs,
+    clippy::items_after_test_module
+)]
 mod tests {
     use super::Puzzle;
     use super::Solution;
@@ -148,7 +146,7 @@ mod tests {
 fn build_shapes_for_ui(
     input: String,
     request: ui_support::DisplayRequest,
-) -> ui_support::DisplayResult {
+) -> Result<ui_support::DisplayResult, String> {
     use egui::{
         epaint::{CircleShape, Color32, Pos2, Rect, RectShape, Shape, Vec2},
         Stroke,
@@ -157,46 +155,34 @@ fn build_shapes_for_ui(
     let section_index = request.result_index;
 
     let sections = input.split("\n\n").collect::<Vec<&str>>();
-    let section = sections[section_index % sections.len()];
-
-    let mut shapes = section
-        .lines()
-        .enumerate()
-        .into_iter()
-        .flat_map(|(y, row)| {
-            row.chars().enumerate().map(move |(x, c)| {
-                if c == '#' {
-                    Shape::Rect(RectShape::stroke(
-                        Rect::from_min_size(
-                            Pos2::new(x as f32 + 0.1, y as f32 + 0.1),
-                            Vec2::new(0.8, 0.8),
-                        ),
-                        0.0,
-                        Stroke::new(0.05, Color32::WHITE),
-                    ))
-                } else {
-                    Shape::Circle(CircleShape::stroke(
-                        Pos2::new(x as f32 + 0.5, y as f32 + 0.5),
-                        0.1,
-                        Stroke::new(0.05, Color32::WHITE),
-                    ))
-                }
-            })
-        })
-        .collect::<Vec<Shape>>();
+    let grid = parse_section(sections[section_index % sections.len()])?;
+
+    let mut shapes = ui_support::render_grid(&grid, |rock, pos| {
+        let shape = if *rock {
+            Shape::Rect(RectShape::stroke(
+                Rect::from_center_size(pos, Vec2::new(0.8, 0.8)),
+                0.0,
+                Stroke::new(0.05, Color32::WHITE),
+            ))
+        } else {
+            Shape::Circle(CircleShape::stroke(
+                pos,
+                0.1,
+                Stroke::new(0.05, Color32::WHITE),
+            ))
+        };
+        vec![Some(shape.into())]
+    });
 
-    let get_mirror_line = |section: &str, part_b: bool, color: Color32| {
-        let mirror = parse_section(section, part_b);
+    let get_mirror_line = |grid: &Grid<bool>, part_b: bool, color: Color32| {
+        let mirror = find_mirror(grid, part_b);
         let points = if mirror < 100 {
             let start = Pos2::new(mirror as f32, 0.0);
-            let end = Pos2::new(mirror as f32, section.lines().count() as f32);
+            let end = Pos2::new(mirror as f32, grid.height() as f32);
             [start, end]
         } else {
             let start = Pos2::new(0.0, (mirror / 100) as f32);
-            let end = Pos2::new(
-                section.chars().position(|c| c == '\n').unwrap() as f32,
-                (mirror / 100) as f32,
-            );
+            let end = Pos2::new(grid.width() as f32, (mirror / 100) as f32);
             [start, end]
         };
         Shape::LineSegment {
@@ -205,12 +191,12 @@ fn build_shapes_for_ui(
         }
     };
 
-    shapes.push(get_mirror_line(section, false, Color32::GREEN));
-    shapes.push(get_mirror_line(section, true, Color32::RED));
+    shapes.push(get_mirror_line(&grid, false, Color32::GREEN).into());
+    shapes.push(get_mirror_line(&grid, true, Color32::RED).into());
 
-    ui_support::DisplayResult {
+    Ok(ui_support::DisplayResult {
         result_count: Some(sections.len()),
         result_index: request.result_index,
-        shapes: shapes.into_iter().map(|s| s.into()).collect(),
-    }
+        shapes,
+    })
 }
@@ -0,0 +1,282 @@
+use super::{Parameter, ParameterField, PuzzleInfo, Registration, Solution};
+use common::{cycle, geom::Direction4, Answer, Grid};
+
+pub struct Puzzle {
+    cycles: usize,
+}
+
+impl Default for Puzzle {
+    fn default() -> Self {
+        Self {
+            cycles: 1_000_000_000,
+        }
+    }
+}
+
+pub(super) const REGISTRATION: Registration = Registration::new::<Puzzle>(PuzzleInfo {
+    year: 2023,
+    day: 14,
+    title: "Parabolic Reflector Dish",
+    has_visualization: false,
+    parameters: &[Parameter {
+        name: "cycles",
+        description: "Number of spin cycles in part B",
+    }],
+    assumptions: &[],
+    exports: &[],
+});
+
+impl Solution for Puzzle {
+    fn solve_a(&mut self, input: String) -> Result<Answer, String> {
+        let (platform, boulders) = parse_input(&input)?;
+        let boulders = platform.tilt(&boulders, Direction4::Up);
+        Answer::from(platform.weight(&boulders)).into()
+    }
+
+    fn solve_b(&mut self, input: String) -> Result<Answer, String> {
+        let (platform, boulders) = parse_input(&input)?;
+        let boulders = cycle::state_at(boulders, |b| platform.spin_cycle(b), self.cycles);
+        Answer::from(platform.weight(&boulders)).into()
+    }
+
+    fn parameters(&mut self) -> Vec<ParameterField<'_>> {
+        vec![ParameterField::new_unsigned("cycles", &mut self.cycles)]
+    }
+
+    #[cfg(feature = "ui")]
+    fn get_shapes(
+        &mut self,
+        _input: String,
+        _request: ui_support::DisplayRequest,
+    ) -> Option<ui_support::DisplayResult> {
+        None
+    }
+}
+
+/// Largest platform side that fits in a single bitset word
+const MAX_SIDE: usize = u128::BITS as usize;
+
+fn parse_input(input: &str) -> Result<(Platform, Boulders), String> {
+    let grid = Grid::try_parse(input.trim(), |c| match c {
+        'O' => Ok(Cell::Boulder),
+        '#' => Ok(Cell::Rock),
+        '.' => Ok(Cell::Space),
+        _ => Err(format!("Invalid cell: {}", c)),
+    })?;
+    if grid.width() > MAX_SIDE || grid.height() > MAX_SIDE {
+        return Err(format!(
+            "Platform of {}x{} is larger than {}x{}",
+            grid.width(),
+            grid.height(),
+            MAX_SIDE,
+            MAX_SIDE
+        ));
+    }
+
+    let mut cubes = vec![0; grid.height()];
+    let mut boulders = vec![0; grid.height()];
+    for (y, row) in grid.rows().enumerate() {
+        for (x, cell) in row.iter().enumerate() {
+            match cell {
+                Cell::Boulder => boulders[y] |= 1 << x,
+                Cell::Rock => cubes[y] |= 1 << x,
+                Cell::Space => {}
+            }
+        }
+    }
+    let platform = Platform::new(grid.width(), cubes);
+    Ok((platform, Boulders { rows: boulders }))
+}
+
+/// Round rocks as one bitset per row, bit x of row y is set for a boulder at (x, y)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Boulders {
+    rows: Vec<u128>,
+}
+
+/// Run of cells between cube rocks or the edge of the platform, where boulders roll freely
+#[derive(Debug)]
+struct Segment {
+    start: u32,
+    end: u32,
+    mask: u128,
+}
+
+/// Cube rocks of the platform, split into segments along every row and column
+#[derive(Debug)]
+struct Platform {
+    width: usize,
+    height: usize,
+    row_segments: Vec<Vec<Segment>>,
+    column_segments: Vec<Vec<Segment>>,
+}
+
+impl Platform {
+    fn new(width: usize, cube_rows: Vec<u128>) -> Self {
+        let height = cube_rows.len();
+        let cube_columns = transpose(&cube_rows, width);
+        Self {
+            width,
+            height,
+            row_segments: cube_rows.iter().map(|&r| segments(r, width)).collect(),
+            column_segments: cube_columns.iter().map(|&c| segments(c, height)).collect(),
+        }
+    }
+
+    /// Tilts the platform north, west, south and east
+    fn spin_cycle(&self, boulders: &Boulders) -> Boulders {
+        [
+            Direction4::Up,
+            Direction4::Left,
+            Direction4::Down,
+            Direction4::Right,
+        ]
+        .into_iter()
+        .fold(boulders.clone(), |b, direction| self.tilt(&b, direction))
+    }
+
+    fn tilt(&self, boulders: &Boulders, direction: Direction4) -> Boulders {
+        let towards_start = matches!(direction, Direction4::Up | Direction4::Left);
+        let rows = match direction {
+            Direction4::Up | Direction4::Down => {
+                let columns = transpose(&boulders.rows, self.width);
+                let columns = roll(&columns, &self.column_segments, towards_start);
+                transpose(&columns, self.height)
+            }
+            Direction4::Left | Direction4::Right => {
+                roll(&boulders.rows, &self.row_segments, towards_start)
+            }
+        };
+        Boulders { rows }
+    }
+
+    /// Load on the north support beams
+    fn weight(&self, boulders: &Boulders) -> usize {
+        boulders
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(y, row)| (self.height - y) * row.count_ones() as usize)
+            .sum()
+    }
+}
+
+/// Splits a line of `length` cells into the segments between the set bits of `cubes`
+fn segments(cubes: u128, length: usize) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut start = 0;
+    for end in (0..length as u32)
+        .filter(|&i| cubes & 1 << i != 0)
+        .chain([length as u32])
+    {
+        if end > start {
+            segments.push(Segment {
+                start,
+                end,
+                mask: ones(end - start) << start,
+            });
+        }
+        start = end + 1;
+    }
+    segments
+}
+
+/// Rolls the boulders in every line to one end of their segments
+fn roll(lines: &[u128], segments: &[Vec<Segment>], towards_start: bool) -> Vec<u128> {
+    lines
+        .iter()
+        .zip(segments)
+        .map(|(&line, segments)| {
+            segments
+                .iter()
+                .map(|segment| {
+                    let count = (line & segment.mask).count_ones();
+                    if towards_start {
+                        ones(count) << segment.start
+                    } else {
+                        ones(count) << (segment.end - count)
+                    }
+                })
+                .fold(0, |line, bits| line | bits)
+        })
+        .collect()
+}
+
+/// Swaps rows and columns, `length` is the number of bits used in each line
+fn transpose(lines: &[u128], length: usize) -> Vec<u128> {
+    let mut transposed = vec![0; length];
+    for (i, &line) in lines.iter().enumerate() {
+        let mut bits = line;
+        while bits != 0 {
+            transposed[bits.trailing_zeros() as usize] |= 1 << i;
+            bits &= bits - 1;
+        }
+    }
+    transposed
+}
+
+/// Word with the lowest `count` bits set
+fn ones(count: u32) -> u128 {
+    u128::MAX.checked_shr(u128::BITS - count).unwrap_or(0)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Cell {
+    Boulder,
+    Rock,
+    Space,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Puzzle;
+    use super::Solution;
+    use common::Answer;
+
+    const TEST_INPUT: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+    #[tokio::test]
+    async fn part_a() {
+        let mut puzzle = Puzzle::default();
+        assert_eq!(
+            puzzle.solve_a(String::from(TEST_INPUT)),
+            Ok(Answer::from(136))
+        )
+    }
+
+    #[tokio::test]
+    async fn part_b() {
+        let mut puzzle = Puzzle::default();
+        assert_eq!(
+            puzzle.solve_b(String::from(TEST_INPUT)),
+            Ok(Answer::from(64))
+        );
+
+        for (cycles, weight) in [
+            (1, 87),
+            (2, 69),
+            (5, 65),
+            (8, 63),
+            (9, 68),
+            (usize::MAX, 63),
+        ] {
+            let mut puzzle = Puzzle { cycles };
+            assert_eq!(
+                puzzle.solve_b(String::from(TEST_INPUT)),
+                Ok(Answer::from(weight))
+            );
+        }
+
+        let wide = ".".repeat(129);
+        assert!(puzzle.solve_b(wide).is_err());
+    }
+}
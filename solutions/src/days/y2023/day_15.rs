@@ -3,12 +3,22 @@ use ahash::AHashMap as HashMap;
 #[cfg(not(feature = "performance"))]
 use std::collections::HashMap;
 
-use super::Solution;
+use super::{PuzzleInfo, Registration, Solution};
 use common::Answer;
 
 #[derive(Default)]
 pub struct Puzzle;
 
+pub(super) const REGISTRATION: Registration = Registration::new::<Puzzle>(PuzzleInfo {
+    year: 2023,
+    day: 15,
+    title: "Lens Library",
+    has_visualization: false,
+    parameters: &[],
+    assumptions: &[],
+    exports: &[],
+});
+
 impl Solution for Puzzle {
     fn solve_a(&mut self, input: String) -> Result<Answer, String> {
         Answer::from(
@@ -105,6 +115,7 @@ impl Lens {
 }
 
 #[cfg(test)]
+#[allow(clippy::default_constructed_unit_structs)]
 mod tests {
     use super::Puzzle;
     use super::Solution;
@@ -2,45 +2,52 @@
 use ahash::AHashSet as HashSet;
 #[cfg(not(feature = "performance"))]
 use std::collections::HashSet;
-use std::hash::Hash;
 
-use super::Solution;
-use common::Answer;
+use super::{PuzzleInfo, Registration, Solution};
+use common::{geom::Direction4, Answer, Grid};
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
 #[derive(Default)]
 pub struct Puzzle;
 
 type Pos = (usize, usize);
-type Dimensions = (usize, usize);
-type Grid = Vec<Vec<Cell>>;
+
+pub(super) const REGISTRATION: Registration = Registration::new::<Puzzle>(PuzzleInfo {
+    year: 2023,
+    day: 16,
+    title: "The Floor Will Be Lava",
+    has_visualization: true,
+    parameters: &[],
+    assumptions: &[],
+    exports: &[],
+});
 
 impl Solution for Puzzle {
     fn solve_a(&mut self, input: String) -> Result<Answer, String> {
-        let grid = parse_input(&input);
+        let grid = parse_input(&input)?;
         Answer::from(find_energized_tile_count(
             &grid,
             Beam {
                 pos: (0, 0),
-                dir: Direction::Right,
+                dir: Direction4::Right,
             },
         ))
         .into()
     }
 
     fn solve_b(&mut self, input: String) -> Result<Answer, String> {
-        let grid = parse_input(&input);
-        let dimensions: Dimensions = (grid[0].len(), grid.len());
+        let grid = parse_input(&input)?;
+        let dimensions = (grid.width(), grid.height());
         let mut beams: Vec<Beam> = (0..dimensions.1)
             .flat_map(|y| {
                 vec![
                     Beam {
                         pos: (0, y),
-                        dir: Direction::Right,
+                        dir: Direction4::Right,
                     },
                     Beam {
                         pos: (dimensions.0 - 1, y),
-                        dir: Direction::Left,
+                        dir: Direction4::Left,
                     },
                 ]
             })
@@ -49,11 +56,11 @@ impl Solution for Puzzle {
             vec![
                 Beam {
                     pos: (x, 0),
-                    dir: Direction::Down,
+                    dir: Direction4::Down,
                 },
                 Beam {
                     pos: (x, dimensions.1 - 1),
-                    dir: Direction::Up,
+                    dir: Direction4::Up,
                 },
             ]
         }));
@@ -70,23 +77,22 @@ impl Solution for Puzzle {
         input: String,
         _request: ui_support::DisplayRequest,
     ) -> Option<ui_support::DisplayResult> {
-        Some(build_shapes_for_ui(input).into())
+        build_shapes_for_ui(input).map(|s| s.into()).ok()
     }
 }
 
-fn find_energized_tile_count(grid: &Grid, start_beam: Beam) -> usize {
+fn find_energized_tile_count(grid: &Grid<Cell>, start_beam: Beam) -> usize {
     run_beam(grid, start_beam).len()
 }
 
-fn run_beam(grid: &Grid, start_beam: Beam) -> HashSet<Pos> {
-    let dimensions: Dimensions = (grid[0].len(), grid.len());
-    let mut visited: HashSet<(usize, usize, Direction)> =
+fn run_beam(grid: &Grid<Cell>, start_beam: Beam) -> HashSet<Pos> {
+    let mut visited: HashSet<(usize, usize, Direction4)> =
         HashSet::from_iter(vec![(start_beam.pos.0, start_beam.pos.1, start_beam.dir)]);
     let mut beams = vec![start_beam];
     while !beams.is_empty() {
         beams = beams
             .into_iter()
-            .flat_map(|b| b.mv(grid, &dimensions))
+            .flat_map(|b| b.mv(grid))
             .filter(|b| !visited.contains(&(b.pos.0, b.pos.1, b.dir)))
             .collect();
         visited.extend(beams.iter().map(|b| (b.pos.0, b.pos.1, b.dir)));
@@ -100,75 +106,75 @@ fn run_beam(grid: &Grid, start_beam: Beam) -> HashSet<Pos> {
 #[derive(Clone)]
 struct Beam {
     pos: Pos,
-    dir: Direction,
+    dir: Direction4,
 }
 impl Beam {
-    pub fn mv(&self, grid: &Grid, dimensions: &Dimensions) -> Vec<Beam> {
-        let cell = &grid[self.pos.1][self.pos.0];
+    pub fn mv(&self, grid: &Grid<Cell>) -> Vec<Beam> {
+        let cell = &grid[self.pos];
         let mut beams = vec![];
         match cell {
-            Cell::VSplit if self.dir == Direction::Left || self.dir == Direction::Right => {
-                if let Some(beam) = move_pos(&self.pos, &Direction::Up, dimensions) {
+            Cell::VSplit if self.dir == Direction4::Left || self.dir == Direction4::Right => {
+                if let Some(beam) = move_pos(grid, &self.pos, &Direction4::Up) {
                     beams.push(beam)
                 }
-                if let Some(beam) = move_pos(&self.pos, &Direction::Down, dimensions) {
+                if let Some(beam) = move_pos(grid, &self.pos, &Direction4::Down) {
                     beams.push(beam)
                 }
             }
-            Cell::HSplit if self.dir == Direction::Up || self.dir == Direction::Down => {
-                if let Some(beam) = move_pos(&self.pos, &Direction::Left, dimensions) {
+            Cell::HSplit if self.dir == Direction4::Up || self.dir == Direction4::Down => {
+                if let Some(beam) = move_pos(grid, &self.pos, &Direction4::Left) {
                     beams.push(beam)
                 }
-                if let Some(beam) = move_pos(&self.pos, &Direction::Right, dimensions) {
+                if let Some(beam) = move_pos(grid, &self.pos, &Direction4::Right) {
                     beams.push(beam)
                 }
             }
             Cell::DownRightMirror => match self.dir {
-                Direction::Right => {
-                    if let Some(beam) = move_pos(&self.pos, &Direction::Down, dimensions) {
+                Direction4::Right => {
+                    if let Some(beam) = move_pos(grid, &self.pos, &Direction4::Down) {
                         beams.push(beam)
                     }
                 }
-                Direction::Down => {
-                    if let Some(beam) = move_pos(&self.pos, &Direction::Right, dimensions) {
+                Direction4::Down => {
+                    if let Some(beam) = move_pos(grid, &self.pos, &Direction4::Right) {
                         beams.push(beam)
                     }
                 }
-                Direction::Left => {
-                    if let Some(beam) = move_pos(&self.pos, &Direction::Up, dimensions) {
+                Direction4::Left => {
+                    if let Some(beam) = move_pos(grid, &self.pos, &Direction4::Up) {
                         beams.push(beam)
                     }
                 }
-                Direction::Up => {
-                    if let Some(beam) = move_pos(&self.pos, &Direction::Left, dimensions) {
+                Direction4::Up => {
+                    if let Some(beam) = move_pos(grid, &self.pos, &Direction4::Left) {
                         beams.push(beam)
                     }
                 }
             },
             Cell::UpRightMirror => match self.dir {
-                Direction::Right => {
-                    if let Some(beam) = move_pos(&self.pos, &Direction::Up, dimensions) {
+                Direction4::Right => {
+                    if let Some(beam) = move_pos(grid, &self.pos, &Direction4::Up) {
                         beams.push(beam)
                     }
                 }
-                Direction::Down => {
-                    if let Some(beam) = move_pos(&self.pos, &Direction::Left, dimensions) {
+                Direction4::Down => {
+                    if let Some(beam) = move_pos(grid, &self.pos, &Direction4::Left) {
                         beams.push(beam)
                     }
                 }
-                Direction::Left => {
-                    if let Some(beam) = move_pos(&self.pos, &Direction::Down, dimensions) {
+                Direction4::Left => {
+                    if let Some(beam) = move_pos(grid, &self.pos, &Direction4::Down) {
                         beams.push(beam)
                     }
                 }
-                Direction::Up => {
-                    if let Some(beam) = move_pos(&self.pos, &Direction::Right, dimensions) {
+                Direction4::Up => {
+                    if let Some(beam) = move_pos(grid, &self.pos, &Direction4::Right) {
                         beams.push(beam)
                     }
                 }
             },
             _ => {
-                if let Some(beam) = move_pos(&self.pos, &self.dir, dimensions) {
+                if let Some(beam) = move_pos(grid, &self.pos, &self.dir) {
                     beams.push(beam)
                 }
             }
@@ -177,43 +183,18 @@ impl Beam {
     }
 }
 
-fn move_pos(pos: &Pos, dir: &Direction, dimensions: &Dimensions) -> Option<Beam> {
-    match dir {
-        Direction::Right if pos.0 < dimensions.0 - 1 => Some(Beam {
-            pos: (pos.0 + 1, pos.1),
-            dir: *dir,
-        }),
-        Direction::Down if pos.1 < dimensions.1 - 1 => Some(Beam {
-            pos: (pos.0, pos.1 + 1),
-            dir: *dir,
-        }),
-        Direction::Left if pos.0 > 0 => Some(Beam {
-            pos: (pos.0 - 1, pos.1),
-            dir: *dir,
-        }),
-        Direction::Up if pos.1 > 0 => Some(Beam {
-            pos: (pos.0, pos.1 - 1),
-            dir: *dir,
-        }),
-        _ => None,
-    }
+fn move_pos(grid: &Grid<Cell>, pos: &Pos, dir: &Direction4) -> Option<Beam> {
+    grid.step(*pos, *dir).map(|pos| Beam { pos, dir: *dir })
 }
 
-fn parse_input(input: &str) -> Grid {
-    input
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| match c {
-                    '|' => Cell::VSplit,
-                    '-' => Cell::HSplit,
-                    '\\' => Cell::DownRightMirror,
-                    '/' => Cell::UpRightMirror,
-                    _ => Cell::Empty,
-                })
-                .collect()
-        })
-        .collect()
+fn parse_input(input: &str) -> Result<Grid<Cell>, String> {
+    Grid::parse(input, |c| match c {
+        '|' => Cell::VSplit,
+        '-' => Cell::HSplit,
+        '\\' => Cell::DownRightMirror,
+        '/' => Cell::UpRightMirror,
+        _ => Cell::Empty,
+    })
 }
 
 #[derive(Debug)]
@@ -225,15 +206,11 @@ enum Cell {
     Empty,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-enum Direction {
-    Right,
-    Down,
-    Left,
-    Up,
-}
-
 #[cfg(test)]
+#[allow(
+    clippy::default_constructed_unit_structs,
+    clippy::items_after_test_module
+)]
 mod tests {
     use super::Puzzle;
     use super::Solution;
@@ -270,21 +247,21 @@ mod tests {
 }
 
 #[cfg(feature = "ui")]
-fn build_shapes_for_ui(input: String) -> Vec<ui_support::DisplayData> {
+fn build_shapes_for_ui(input: String) -> Result<Vec<ui_support::DisplayData>, String> {
     use egui::epaint::{CircleShape, Color32, Pos2, Shape, Stroke};
 
-    let grid = parse_input(&input);
+    let grid = parse_input(&input)?;
     let energized = run_beam(
         &grid,
         Beam {
             pos: (0, 0),
-            dir: Direction::Right,
+            dir: Direction4::Right,
         },
     );
     let mirror_color = Color32::from_rgb(255, 0, 0);
     let path_color = Color32::from_rgba_premultiplied(0, 255, 0, 75);
 
-    ui_support::render_grid(&grid, move |cell, pos| {
+    Ok(ui_support::render_grid(&grid, move |cell, pos| {
         let mut shapes: Vec<Option<ui_support::DisplayData>> = vec![match cell {
             Cell::HSplit => Some(
                 Shape::LineSegment {
@@ -328,5 +305,5 @@ fn build_shapes_for_ui(input: String) -> Vec<ui_support::DisplayData> {
             ));
         }
         shapes
-    })
+    }))
 }
@@ -0,0 +1,408 @@
+use super::{Parameter, ParameterField, PuzzleInfo, Registration, Solution};
+use common::{search::SearchResult, Answer, Grid};
+
+pub struct Puzzle {
+    min_steps_a: usize,
+    max_steps_a: usize,
+    min_steps_b: usize,
+    max_steps_b: usize,
+}
+
+impl Default for Puzzle {
+    fn default() -> Self {
+        Self {
+            min_steps_a: 1,
+            max_steps_a: 3,
+            min_steps_b: 4,
+            max_steps_b: 10,
+        }
+    }
+}
+
+type Coord = (usize, usize);
+
+pub(super) const REGISTRATION: Registration = Registration::new::<Puzzle>(PuzzleInfo {
+    year: 2023,
+    day: 17,
+    title: "Clumsy Crucible",
+    has_visualization: true,
+    parameters: &[
+        Parameter {
+            name: "min_steps_a",
+            description: "Fewest blocks the crucible moves in a straight line in part A",
+        },
+        Parameter {
+            name: "max_steps_a",
+            description: "Most blocks the crucible moves in a straight line in part A",
+        },
+        Parameter {
+            name: "min_steps_b",
+            description: "Fewest blocks the ultra crucible moves in a straight line in part B",
+        },
+        Parameter {
+            name: "max_steps_b",
+            description: "Most blocks the ultra crucible moves in a straight line in part B",
+        },
+    ],
+    assumptions: &[],
+    exports: &[],
+});
+
+impl Solution for Puzzle {
+    fn solve_a(&mut self, input: String) -> Result<Answer, String> {
+        let grid = parse_input(&input)?;
+        let path =
+            Crucible::new(&grid, self.min_steps_a, self.max_steps_a)?.find_shortest_path()?;
+        Answer::from(path.cost).into()
+    }
+
+    fn solve_b(&mut self, input: String) -> Result<Answer, String> {
+        let grid = parse_input(&input)?;
+        let path =
+            Crucible::new(&grid, self.min_steps_b, self.max_steps_b)?.find_shortest_path()?;
+        Answer::from(path.cost).into()
+    }
+
+    fn parameters(&mut self) -> Vec<ParameterField<'_>> {
+        vec![
+            ParameterField::new_unsigned("min_steps_a", &mut self.min_steps_a),
+            ParameterField::new_unsigned("max_steps_a", &mut self.max_steps_a),
+            ParameterField::new_unsigned("min_steps_b", &mut self.min_steps_b),
+            ParameterField::new_unsigned("max_steps_b", &mut self.max_steps_b),
+        ]
+    }
+
+    #[cfg(feature = "ui")]
+    fn get_shapes(
+        &mut self,
+        input: String,
+        _request: ui_support::DisplayRequest,
+    ) -> Option<ui_support::DisplayResult> {
+        build_shapes_for_ui(input, self).map(|s| s.into()).ok()
+    }
+}
+
+fn parse_input(input: &str) -> Result<Grid<usize>, String> {
+    Grid::try_parse(input, |c| {
+        c.to_digit(10)
+            .filter(|d| *d > 0)
+            .map(|d| d as usize)
+            .ok_or(format!("Invalid heat loss, expected 1 to 9: {}", c))
+    })
+}
+
+/// Axis of the last move, the crucible has to turn so its next move is along the other one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Axis {
+    Horizontal = 0,
+    Vertical = 1,
+}
+
+impl Axis {
+    fn other(self) -> Self {
+        match self {
+            Self::Horizontal => Self::Vertical,
+            Self::Vertical => Self::Horizontal,
+        }
+    }
+}
+
+/// Marks states without a predecessor in the flat arrays of the search
+const NONE: usize = usize::MAX;
+
+struct Crucible<'a> {
+    min_steps: usize,
+    max_steps: usize,
+    grid: &'a Grid<usize>,
+}
+
+impl<'a> Crucible<'a> {
+    fn new(grid: &'a Grid<usize>, min: usize, max: usize) -> Result<Self, String> {
+        if max == 0 || min > max {
+            return Err(format!(
+                "Cannot move between {} and {} blocks in a straight line",
+                min, max
+            ));
+        }
+        Ok(Self {
+            min_steps: min.max(1),
+            max_steps: max,
+            grid,
+        })
+    }
+
+    /// Index of a state in the flat arrays, by position and the axis it was reached along
+    fn index(&self, (x, y): Coord, axis: Axis) -> usize {
+        (y * self.grid.width() + x) * 2 + axis as usize
+    }
+
+    fn state(&self, index: usize) -> (Coord, Axis) {
+        let cell = index / 2;
+        let axis = if index.is_multiple_of(2) {
+            Axis::Horizontal
+        } else {
+            Axis::Vertical
+        };
+        ((cell % self.grid.width(), cell / self.grid.width()), axis)
+    }
+
+    /// A* over (x, y, axis) states, guided by the Manhattan distance to the exit, as every block
+    /// loses at least 1 heat; the path holds the blocks where the crucible turns
+    fn find_shortest_path(&self) -> Result<SearchResult<Coord, usize>, String> {
+        if self.grid.is_empty() {
+            return Err(String::from("Empty grid"));
+        }
+        let (width, height) = (self.grid.width(), self.grid.height());
+        let exit = (width - 1, height - 1);
+        let heuristic = |(x, y): Coord| exit.0 - x + exit.1 - y;
+
+        let mut costs = vec![NONE; width * height * 2];
+        let mut previous = vec![NONE; width * height * 2];
+        let mut done = vec![false; width * height * 2];
+        let mut queue = BucketQueue::default();
+        for axis in [Axis::Horizontal, Axis::Vertical] {
+            let start = self.index((0, 0), axis);
+            costs[start] = 0;
+            queue.push(heuristic((0, 0)), start);
+        }
+
+        while let Some(index) = queue.pop() {
+            if done[index] {
+                continue;
+            }
+            done[index] = true;
+            let (pos, axis) = self.state(index);
+            if pos == exit {
+                let mut path = vec![pos];
+                let mut current = index;
+                while previous[current] != NONE {
+                    current = previous[current];
+                    path.push(self.state(current).0);
+                }
+                path.reverse();
+                return Ok(SearchResult {
+                    cost: costs[index],
+                    path,
+                });
+            }
+
+            let axis = axis.other();
+            let deltas: [(isize, isize); 2] = match axis {
+                Axis::Horizontal => [(1, 0), (-1, 0)],
+                Axis::Vertical => [(0, 1), (0, -1)],
+            };
+            for (dx, dy) in deltas {
+                let mut cost = costs[index];
+                let mut next = pos;
+                for step in 1..=self.max_steps {
+                    let Some(cell) = self.grid.offset(next, (dx, dy)) else {
+                        break;
+                    };
+                    next = cell;
+                    cost += self.grid[next];
+                    let next_index = self.index(next, axis);
+                    if step >= self.min_steps && cost < costs[next_index] {
+                        costs[next_index] = cost;
+                        previous[next_index] = index;
+                        queue.push(cost + heuristic(next), next_index);
+                    }
+                }
+            }
+        }
+        Err(String::from("No path to the exit"))
+    }
+}
+
+/// Monotone priority queue with one bucket per priority; works because the heuristic is
+/// consistent, so nothing is pushed below the priority that was popped last
+#[derive(Default)]
+struct BucketQueue {
+    buckets: Vec<Vec<usize>>,
+    current: usize,
+}
+
+impl BucketQueue {
+    fn push(&mut self, priority: usize, item: usize) {
+        debug_assert!(
+            priority >= self.current,
+            "Priority below the last one popped"
+        );
+        if priority >= self.buckets.len() {
+            self.buckets.resize_with(priority + 1, Vec::new);
+        }
+        self.buckets[priority].push(item);
+    }
+
+    fn pop(&mut self) -> Option<usize> {
+        while self.current < self.buckets.len() {
+            if let Some(item) = self.buckets[self.current].pop() {
+                return Some(item);
+            }
+            self.current += 1;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::items_after_test_module)]
+mod tests {
+    use super::Puzzle;
+    use super::Solution;
+    use common::Answer;
+
+    const TEST_INPUT: &str = "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+
+    #[tokio::test]
+    async fn part_a() {
+        let mut puzzle = Puzzle::default();
+        assert_eq!(
+            puzzle.solve_a(String::from(TEST_INPUT)),
+            Ok(Answer::from(102))
+        );
+
+        for (min, max, heat_loss) in [(2, 5, 101), (1, 12, 78), (3, 7, 112)] {
+            let mut puzzle = Puzzle {
+                min_steps_a: min,
+                max_steps_a: max,
+                ..Default::default()
+            };
+            assert_eq!(
+                puzzle.solve_a(String::from(TEST_INPUT)),
+                Ok(Answer::from(heat_loss))
+            );
+        }
+
+        let mut puzzle = Puzzle {
+            min_steps_a: 5,
+            max_steps_a: 5,
+            ..Default::default()
+        };
+        assert!(puzzle.solve_a(String::from(TEST_INPUT)).is_err());
+        let mut puzzle = Puzzle {
+            min_steps_a: 4,
+            max_steps_a: 2,
+            ..Default::default()
+        };
+        assert!(puzzle.solve_a(String::from(TEST_INPUT)).is_err());
+
+        let mut puzzle = Puzzle::default();
+        assert_eq!(
+            puzzle.solve_a(String::new()),
+            Err(String::from("Empty grid"))
+        );
+        assert!(puzzle.solve_a(String::from("120\n311")).is_err());
+    }
+
+    #[tokio::test]
+    async fn part_b() {
+        let mut puzzle = Puzzle::default();
+        assert_eq!(
+            puzzle.solve_b(String::from(TEST_INPUT)),
+            Ok(Answer::from(94))
+        );
+        assert_eq!(
+            puzzle.solve_b(String::from(
+                "111111111111
+999999999991
+999999999991
+999999999991
+999999999991"
+            )),
+            Ok(Answer::from(71))
+        );
+
+        let mut puzzle = Puzzle {
+            min_steps_b: 6,
+            max_steps_b: 20,
+            ..Default::default()
+        };
+        assert_eq!(
+            puzzle.solve_b(String::from(TEST_INPUT)),
+            Ok(Answer::from(79))
+        );
+    }
+}
+
+#[cfg(feature = "ui")]
+fn build_shapes_for_ui(
+    input: String,
+    puzzle: &Puzzle,
+) -> Result<Vec<ui_support::DisplayData>, String> {
+    use egui::epaint::{Color32, Rect, RectShape, Shape, Stroke};
+
+    let grid = parse_input(&input)?;
+    let gradient = [
+        Color32::from_rgb(26, 152, 80),
+        Color32::from_rgb(102, 189, 99),
+        Color32::from_rgb(166, 217, 106),
+        Color32::from_rgb(217, 239, 139),
+        Color32::from_rgb(255, 255, 191),
+        Color32::from_rgb(254, 224, 139),
+        Color32::from_rgb(253, 174, 97),
+        Color32::from_rgb(244, 109, 67),
+        Color32::from_rgb(215, 48, 39),
+    ];
+
+    let mut shapes: Vec<ui_support::DisplayData> =
+        ui_support::render_grid(&grid, move |cell, pos| {
+            vec![Some(
+                Shape::Rect(RectShape::filled(
+                    Rect::from_center_size(pos, (1.0, 1.0).into()),
+                    0.0,
+                    gradient[cell - 1],
+                ))
+                .into(),
+            )]
+        });
+
+    let draw_path = |grid: &Grid<usize>, min: usize, max: usize, offset: f32, color: Color32| {
+        let path = Crucible::new(grid, min, max)?.find_shortest_path()?;
+        let to_pos =
+            |(x, y): Coord| egui::Pos2::new(x as f32 + 0.5 + offset, y as f32 + 0.5 + offset);
+        Ok::<_, String>(
+            path.path
+                .windows(2)
+                .map(|steps| Shape::LineSegment {
+                    points: [to_pos(steps[0]), to_pos(steps[1])],
+                    stroke: Stroke::new(0.2, color),
+                })
+                .collect::<Vec<_>>(),
+        )
+    };
+    shapes.extend(
+        draw_path(
+            &grid,
+            puzzle.min_steps_a,
+            puzzle.max_steps_a,
+            -0.2,
+            Color32::BLUE,
+        )?
+        .into_iter()
+        .map(|s| s.into()),
+    );
+    shapes.extend(
+        draw_path(
+            &grid,
+            puzzle.min_steps_b,
+            puzzle.max_steps_b,
+            0.2,
+            Color32::DARK_BLUE,
+        )?
+        .into_iter()
+        .map(|s| s.into()),
+    );
+    Ok(shapes)
+}
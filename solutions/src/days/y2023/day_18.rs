@@ -1,11 +1,24 @@
 use std::{num::ParseIntError, str::FromStr};
 
-use super::Solution;
-use common::Answer;
+use super::{PuzzleInfo, Registration, Solution};
+use common::{
+    geom::{Direction4, Point2},
+    math, Answer,
+};
 
 #[derive(Default)]
 pub struct Puzzle;
 
+pub(super) const REGISTRATION: Registration = Registration::new::<Puzzle>(PuzzleInfo {
+    year: 2023,
+    day: 18,
+    title: "Lavaduct Lagoon",
+    has_visualization: true,
+    parameters: &[],
+    assumptions: &[],
+    exports: &[],
+});
+
 impl Solution for Puzzle {
     fn solve_a(&mut self, input: String) -> Result<Answer, String> {
         let dig_plans = parse_input(&input);
@@ -37,66 +50,44 @@ fn parse_input(input: &str) -> Vec<DigPlan> {
         .collect()
 }
 
-type DigBorder = Vec<(isize, isize)>;
+type DigBorder = Vec<Point2<i64>>;
 fn dig(plans: Vec<DigPlan>) -> DigBorder {
-    let mut current = (0_isize, 0_isize);
+    let mut current = Point2::new(0_i64, 0_i64);
     let mut ground = Vec::from([current]);
     for plan in plans {
-        match plan.direction {
-            Direction::Down => {
-                current = (current.0, current.1 + plan.distance);
-                ground.push(current);
-            }
-            Direction::Up => {
-                current = (current.0, current.1 - plan.distance);
-                ground.push(current);
-            }
-            Direction::Left => {
-                current = (current.0 - plan.distance, current.1);
-                ground.push(current);
-            }
-            Direction::Right => {
-                current = (current.0 + plan.distance, current.1);
-                ground.push(current);
-            }
-        }
+        current = current
+            .step_n(plan.direction, plan.distance)
+            .expect("Dig plan stays within range");
+        ground.push(current);
     }
     ground
 }
 
-// Shoelace formula
+/// Interior points by Pick's theorem, plus the border itself
 fn dig_area(dig_border: &DigBorder) -> usize {
-    let mut border_length = 0;
-    let (s1, s2) = dig_border
-        .windows(2)
-        .fold((0_isize, 0_isize), |(s1, s2), edges| {
-            border_length +=
-                ((edges[0].0 - edges[1].0).abs() + (edges[0].1 - edges[1].1).abs()).abs();
-            let (c1, c2) = (edges[0], edges[1]);
-            (s1 + c1.0 * c2.1, s2 + c1.1 * c2.0)
-        });
-    let area = ((s1 - s2).abs() + border_length) / 2;
-    (area + 1) as usize
+    let border_length = math::boundary_points(dig_border);
+    let double_area = math::shoelace_double_area(dig_border);
+    (math::interior_points(double_area, border_length) + border_length) as usize
 }
 
 #[derive(Debug, Clone)]
 struct DigPlan {
-    direction: Direction,
-    distance: isize,
+    direction: Direction4,
+    distance: i64,
     color: String,
 }
 
 impl DigPlan {
     fn alt_mode(self) -> Self {
-        let distance = isize::from_str_radix(&self.color[0..5], 16).unwrap();
+        let distance = i64::from_str_radix(&self.color[0..5], 16).unwrap();
         let dir = self.color.chars().nth(5).unwrap();
 
         Self {
             direction: match dir {
-                '0' => Direction::Right,
-                '1' => Direction::Down,
-                '2' => Direction::Left,
-                '3' => Direction::Up,
+                '0' => Direction4::Right,
+                '1' => Direction4::Down,
+                '2' => Direction4::Left,
+                '3' => Direction4::Up,
                 _ => panic!("Unknown direction: {}", dir),
             },
             distance,
@@ -121,36 +112,28 @@ impl FromStr for DigPlan {
             .ok_or("No color suffix")?;
 
         Ok(Self {
-            direction: direction.parse()?,
+            direction: parse_direction(direction)?,
             distance: distance.parse().map_err(|e: ParseIntError| e.to_string())?,
             color: color.into(),
         })
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl FromStr for Direction {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "R" => Ok(Self::Right),
-            "L" => Ok(Self::Left),
-            "U" => Ok(Self::Up),
-            "D" => Ok(Self::Down),
-            _ => Err(format!("Unknown direction: {}", s)),
-        }
+fn parse_direction(s: &str) -> Result<Direction4, String> {
+    match s {
+        "R" => Ok(Direction4::Right),
+        "L" => Ok(Direction4::Left),
+        "U" => Ok(Direction4::Up),
+        "D" => Ok(Direction4::Down),
+        _ => Err(format!("Unknown direction: {}", s)),
     }
 }
 
 #[cfg(test)]
+#[allow(
+    clippy::default_constructed_unit_structs,
+    clippy::items_after_test_module
+)]
 mod tests {
     use super::Puzzle;
     use super::Solution;
@@ -206,8 +189,13 @@ fn build_shapes_for_ui(
     let dig = dig(plans);
     let ranges = dig
         .iter()
-        .fold((0, 0, 0, 0), |(min_x, min_y, max_x, max_y), (x, y)| {
-            (min_x.min(*x), min_y.min(*y), max_x.max(*x), max_y.max(*y))
+        .fold((0, 0, 0, 0), |(min_x, min_y, max_x, max_y), p| {
+            (
+                min_x.min(p.x),
+                min_y.min(p.y),
+                max_x.max(p.x),
+                max_y.max(p.y),
+            )
         });
     let offset: (f32, f32) = (ranges.0 as f32 - 0.5, ranges.1 as f32 - 0.5);
     let largest_range = (ranges.2 - ranges.0).max(ranges.3 - ranges.1) as f32 * scale;
@@ -215,17 +203,17 @@ fn build_shapes_for_ui(
 
     let shapes: Vec<ui_support::DisplayData> = dig
         .windows(2)
-        .map(|coords: &[(isize, isize)]| {
+        .map(|coords: &[Point2<i64>]| {
             let (c1, c2) = (coords[0], coords[1]);
             Shape::LineSegment {
                 points: [
                     egui::Pos2::new(
-                        (c1.0 as f32 - offset.0) * scale,
-                        (c1.1 as f32 - offset.1) * scale,
+                        (c1.x as f32 - offset.0) * scale,
+                        (c1.y as f32 - offset.1) * scale,
                     ),
                     egui::Pos2::new(
-                        (c2.0 as f32 - offset.0) * scale,
-                        (c2.1 as f32 - offset.1) * scale,
+                        (c2.x as f32 - offset.0) * scale,
+                        (c2.y as f32 - offset.1) * scale,
                     ),
                 ],
                 stroke: Stroke::new(stroke_width, Color32::RED),
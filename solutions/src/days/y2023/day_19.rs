@@ -2,15 +2,25 @@
 use ahash::AHashMap as HashMap;
 #[cfg(not(feature = "performance"))]
 use std::collections::HashMap;
-use std::{ops::RangeInclusive, str::FromStr};
+use std::str::FromStr;
 
-use super::Solution;
-use common::Answer;
+use super::{PuzzleInfo, Registration, Solution};
+use common::{interval::Interval, Answer};
 
 #[derive(Default)]
 pub struct Puzzle;
 
-type RangeList = HashMap<char, RangeInclusive<u32>>;
+type RangeList = HashMap<char, Interval<u32>>;
+
+pub(super) const REGISTRATION: Registration = Registration::new::<Puzzle>(PuzzleInfo {
+    year: 2023,
+    day: 19,
+    title: "Aplenty",
+    has_visualization: false,
+    parameters: &[],
+    assumptions: &[],
+    exports: &[],
+});
 
 impl Solution for Puzzle {
     fn solve_a(&mut self, input: String) -> Result<Answer, String> {
@@ -27,10 +37,10 @@ impl Solution for Puzzle {
     fn solve_b(&mut self, input: String) -> Result<Answer, String> {
         let (workflows, _) = parse_input(&input);
         let ranges: RangeList = HashMap::from([
-            ('x', 1..=4000),
-            ('m', 1..=4000),
-            ('a', 1..=4000),
-            ('s', 1..=4000),
+            ('x', Interval::new(1, 4001)),
+            ('m', Interval::new(1, 4001)),
+            ('a', Interval::new(1, 4001)),
+            ('s', Interval::new(1, 4001)),
         ]);
         let start_flow = workflows.get("in").unwrap();
         Answer::from(start_flow.reduce(&ranges, &workflows)).into()
@@ -101,10 +111,7 @@ impl Workflow {
                     let workflow = map.get(&name).unwrap();
                     workflow.reduce(&ranges, map)
                 }
-                RuleTarget::Accept => ranges
-                    .values()
-                    .map(|range| (range.end() - range.start() + 1) as usize)
-                    .product(),
+                RuleTarget::Accept => ranges.values().map(|range| range.len() as usize).product(),
                 RuleTarget::Reject => 0,
             })
             .sum()
@@ -157,39 +164,33 @@ impl RuleType {
         match self {
             Self::Direct(target) => Some((target.clone(), ranges.clone(), ranges.clone())),
             Self::Gt(src, value, target) => {
-                let mut next_range = ranges.clone();
-                let mut remaining_range = ranges.clone();
-                let current_range = ranges.get(src).unwrap();
-                if current_range.end() < value {
-                    return None;
-                }
-                next_range.insert(
-                    *src,
-                    (value + 1).max(*current_range.start())..=*current_range.end(),
-                );
-                remaining_range.insert(
-                    *src,
-                    *current_range.start()..=*value.max(current_range.start()),
-                );
-                Some((target.clone(), next_range, remaining_range))
+                let (remaining, next) = ranges.get(src).unwrap().split_at(value + 1);
+                Self::split_ranges(ranges, *src, target, next, remaining)
             }
             Self::Lt(src, value, target) => {
-                let mut next_range = ranges.clone();
-                let mut remaining_range = ranges.clone();
-                let current_range = ranges.get(src).unwrap();
-                if current_range.start() > value {
-                    return None;
-                }
-                next_range.insert(
-                    *src,
-                    *current_range.start()..=(value - 1).min(*current_range.end()),
-                );
-                remaining_range
-                    .insert(*src, *value.min(current_range.end())..=*current_range.end());
-                Some((target.clone(), next_range, remaining_range))
+                let (next, remaining) = ranges.get(src).unwrap().split_at(*value);
+                Self::split_ranges(ranges, *src, target, next, remaining)
             }
         }
     }
+
+    /// Ranges matching the rule go to `target`, the other ones are left for the next rule
+    fn split_ranges(
+        ranges: &RangeList,
+        src: char,
+        target: &RuleTarget,
+        next: Interval<u32>,
+        remaining: Interval<u32>,
+    ) -> Option<(RuleTarget, RangeList, RangeList)> {
+        if next.is_empty() {
+            return None;
+        }
+        let mut next_ranges = ranges.clone();
+        let mut remaining_ranges = ranges.clone();
+        next_ranges.insert(src, next);
+        remaining_ranges.insert(src, remaining);
+        Some((target.clone(), next_ranges, remaining_ranges))
+    }
 }
 
 impl FromStr for RuleType {
@@ -278,6 +279,7 @@ impl FromStr for Part {
 }
 
 #[cfg(test)]
+#[allow(clippy::default_constructed_unit_structs)]
 mod tests {
     use super::Puzzle;
     use super::Solution;
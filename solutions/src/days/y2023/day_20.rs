@@ -0,0 +1,1000 @@
+use std::{collections::VecDeque, fmt::Write, ops::Range, str::FromStr};
+
+use super::{Export, Parameter, ParameterField, PuzzleInfo, Registration, Solution};
+use common::{
+    cycle::{self, Cycle, CycleStates},
+    math,
+    parse::{self, ParseError},
+    Answer,
+};
+
+pub struct Puzzle {
+    button_presses: usize,
+    press_limit: usize,
+    vcd_first_press: usize,
+    vcd_presses: usize,
+}
+
+impl Default for Puzzle {
+    fn default() -> Self {
+        Self {
+            button_presses: 1000,
+            press_limit: 100_000,
+            vcd_first_press: 1,
+            vcd_presses: 100,
+        }
+    }
+}
+
+pub(super) const REGISTRATION: Registration = Registration::new::<Puzzle>(PuzzleInfo {
+    year: 2023,
+    day: 20,
+    title: "Pulse Propagation",
+    has_visualization: false,
+    parameters: &[
+        Parameter {
+            name: "button_presses",
+            description: "Number of button presses in part A",
+        },
+        Parameter {
+            name: "press_limit",
+            description: "Maximum number of button presses simulated or checked in part B",
+        },
+        Parameter {
+            name: "vcd_first_press",
+            description: "First button press in the VCD export, counted from 1",
+        },
+        Parameter {
+            name: "vcd_presses",
+            description: "Number of button presses in the VCD export",
+        },
+    ],
+    assumptions: &[],
+    exports: &[Export {
+        name: "vcd",
+        description: "Value change dump of the output of every module, for waveform viewers like \
+                      GTKWave",
+    }],
+});
+
+impl Solution for Puzzle {
+    fn solve_a(&mut self, input: String) -> Result<Answer, String> {
+        let network: Network = input.parse()?;
+        let mut state = network.initial_state();
+        let mut pulses = [0_usize; 2];
+        for _ in 0..self.button_presses {
+            network.press(&mut state, |pulse| {
+                pulses[pulse.high as usize] += 1;
+                true
+            });
+        }
+        Answer::from(pulses[0] * pulses[1]).into()
+    }
+
+    fn solve_b(&mut self, input: String) -> Result<Answer, String> {
+        let network: Network = input.parse()?;
+        let rx = network.id("rx").ok_or("Input needs rx output")?;
+        let presses = match Decomposition::new(&network, rx, self.press_limit) {
+            Ok(decomposition) => decomposition.first_low_rx_pulse(self.press_limit)?,
+            Err(reason) => simulate_until_low(&network, rx, self.press_limit).ok_or_else(|| {
+                format!(
+                    "rx did not receive a low pulse within {} button presses, and the network \
+                     cannot be split into counters: {reason}",
+                    self.press_limit
+                )
+            })?,
+        };
+        Answer::from(presses).into()
+    }
+
+    fn parameters(&mut self) -> Vec<ParameterField<'_>> {
+        vec![
+            ParameterField::new_unsigned("button_presses", &mut self.button_presses),
+            ParameterField::new_unsigned("press_limit", &mut self.press_limit),
+            ParameterField::new_unsigned("vcd_first_press", &mut self.vcd_first_press),
+            ParameterField::new_unsigned("vcd_presses", &mut self.vcd_presses),
+        ]
+    }
+
+    fn export(&mut self, input: String, name: &str) -> Result<String, String> {
+        match name {
+            "vcd" => {
+                if self.vcd_first_press == 0 {
+                    return Err(String::from("Button presses are counted from 1"));
+                }
+                let network: Network = input.parse()?;
+                Ok(write_vcd(&network, self.vcd_first_press, self.vcd_presses))
+            }
+            _ => Err(format!("Unknown export: {}", name)),
+        }
+    }
+
+    #[cfg(feature = "ui")]
+    fn get_shapes(
+        &mut self,
+        _input: String,
+        _request: ui_support::DisplayRequest,
+    ) -> Option<ui_support::DisplayResult> {
+        None
+    }
+}
+
+/// Index of a module in its network
+pub(crate) type ModuleId = usize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Kind {
+    Broadcaster,
+    FlipFlop,
+    Conjunction,
+    /// Module that is only named as output, like rx
+    Output,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Module {
+    pub(crate) name: String,
+    pub(crate) kind: Kind,
+    pub(crate) inputs: Vec<ModuleId>,
+    /// Receiving modules, with the state bit they remember this module's pulses in
+    pub(crate) outputs: Vec<(ModuleId, usize)>,
+    /// State bits of the module: the flip-flop state, or the conjunction memory in input order
+    pub(crate) bits: Range<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Pulse {
+    pub(crate) from: ModuleId,
+    pub(crate) to: ModuleId,
+    pub(crate) high: bool,
+    /// Step of the press in which the pulse is sent; the button sends its pulse in step 0
+    pub(crate) time: usize,
+}
+
+/// Flip-flop states and conjunction memories of all modules of a network as bitset, so states
+/// are cheap to copy, hash and compare
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub(crate) struct State {
+    words: Vec<u64>,
+}
+
+impl State {
+    pub(crate) fn new(bits: usize) -> Self {
+        Self {
+            words: vec![0; bits.div_ceil(64)],
+        }
+    }
+
+    pub(crate) fn get(&self, bit: usize) -> bool {
+        self.words[bit / 64] >> (bit % 64) & 1 == 1
+    }
+
+    pub(crate) fn set(&mut self, bit: usize, value: bool) {
+        if value {
+            self.words[bit / 64] |= 1 << (bit % 64);
+        } else {
+            self.words[bit / 64] &= !(1 << (bit % 64));
+        }
+    }
+
+    /// The listed bits of this state, as a state of their own
+    pub(crate) fn gather(&self, bits: &[usize]) -> Self {
+        let mut gathered = Self::new(bits.len());
+        bits.iter()
+            .enumerate()
+            .for_each(|(index, bit)| gathered.set(index, self.get(*bit)));
+        gathered
+    }
+
+    /// Inverse of `gather`
+    pub(crate) fn scatter(&mut self, bits: &[usize], gathered: &Self) {
+        bits.iter()
+            .enumerate()
+            .for_each(|(index, bit)| self.set(*bit, gathered.get(index)));
+    }
+}
+
+/// Module network with all modules in an arena; the state is kept apart, so a single network can
+/// be simulated from any number of states
+#[derive(Debug, Clone)]
+pub(crate) struct Network {
+    pub(crate) modules: Vec<Module>,
+    pub(crate) broadcaster: ModuleId,
+    pub(crate) state_bits: usize,
+}
+
+impl Network {
+    pub(crate) fn id(&self, name: &str) -> Option<ModuleId> {
+        self.modules.iter().position(|m| m.name == name)
+    }
+
+    /// All flip-flops off and all conjunctions remembering low pulses
+    pub(crate) fn initial_state(&self) -> State {
+        State::new(self.state_bits)
+    }
+
+    /// Presses the button once
+    pub(crate) fn press<F>(&self, state: &mut State, deliver: F)
+    where
+        F: FnMut(&Pulse) -> bool,
+    {
+        let start = Pulse {
+            from: self.broadcaster,
+            to: self.broadcaster,
+            high: false,
+            time: 0,
+        };
+        self.send(state, vec![start], deliver);
+    }
+
+    /// Sends the pulses, which have to follow connections of the network or go from the broadcaster
+    /// to itself, and handles all pulses that follow. `deliver` sees every pulse and decides
+    /// whether it arrives.
+    pub(crate) fn send<F>(&self, state: &mut State, pulses: Vec<Pulse>, mut deliver: F)
+    where
+        F: FnMut(&Pulse) -> bool,
+    {
+        let mut queue = VecDeque::from_iter(pulses.into_iter().map(|pulse| {
+            let bit = self.modules[pulse.from]
+                .outputs
+                .iter()
+                .find(|(to, _)| *to == pulse.to)
+                .map_or(0, |(_, bit)| *bit);
+            (pulse, bit)
+        }));
+        while let Some((pulse, bit)) = queue.pop_front() {
+            if !deliver(&pulse) {
+                continue;
+            }
+            let module = &self.modules[pulse.to];
+            let sent = match module.kind {
+                Kind::Broadcaster => Some(pulse.high),
+                Kind::FlipFlop if pulse.high => None,
+                Kind::FlipFlop => {
+                    let on = !state.get(bit);
+                    state.set(bit, on);
+                    Some(on)
+                }
+                Kind::Conjunction => {
+                    state.set(bit, pulse.high);
+                    Some(!module.bits.clone().all(|bit| state.get(bit)))
+                }
+                Kind::Output => None,
+            };
+            if let Some(high) = sent {
+                queue.extend(module.outputs.iter().map(|(to, bit)| {
+                    let next = Pulse {
+                        from: pulse.to,
+                        to: *to,
+                        high,
+                        time: pulse.time + 1,
+                    };
+                    (next, *bit)
+                }));
+            }
+        }
+    }
+}
+
+impl FromStr for Network {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut modules: Vec<Module> = vec![];
+        let mut outputs = vec![];
+        for line in parse::lines(input).filter(|l| !l.text.trim().is_empty()) {
+            let (module, targets) = line.split_once("->")?;
+            let module = module.trim();
+            let (kind, name) = if let Some(name) = module.text.strip_prefix('%') {
+                (Kind::FlipFlop, name)
+            } else if let Some(name) = module.text.strip_prefix('&') {
+                (Kind::Conjunction, name)
+            } else if module.text == "broadcaster" {
+                (Kind::Broadcaster, module.text)
+            } else {
+                return Err(module.error(format!("Unknown module type '{}'", module.text)));
+            };
+            if modules.iter().any(|m| m.name == name) {
+                return Err(module.error(format!("Module {name} is defined twice")));
+            }
+            modules.push(Module {
+                name: name.to_string(),
+                kind,
+                inputs: vec![],
+                outputs: vec![],
+                bits: 0..0,
+            });
+            outputs.push(targets.split(",").map(|t| t.text).collect::<Vec<_>>());
+        }
+        let broadcaster = modules
+            .iter()
+            .position(|m| m.kind == Kind::Broadcaster)
+            .ok_or(ParseError {
+                line: 1,
+                column: 1,
+                message: String::from("Input needs a broadcaster"),
+            })?;
+
+        let mut targets = vec![];
+        for names in outputs {
+            let mut ids = vec![];
+            for name in names {
+                let id = match modules.iter().position(|m| m.name == name) {
+                    Some(id) => id,
+                    None => {
+                        modules.push(Module {
+                            name: name.to_string(),
+                            kind: Kind::Output,
+                            inputs: vec![],
+                            outputs: vec![],
+                            bits: 0..0,
+                        });
+                        modules.len() - 1
+                    }
+                };
+                modules[id].inputs.push(targets.len());
+                ids.push(id);
+            }
+            targets.push(ids);
+        }
+        let mut state_bits = 0;
+        for module in modules.iter_mut() {
+            let bits = match module.kind {
+                Kind::FlipFlop => 1,
+                Kind::Conjunction => module.inputs.len(),
+                _ => 0,
+            };
+            module.bits = state_bits..state_bits + bits;
+            state_bits += bits;
+        }
+        // outputs keep the order in which they are listed, which is the order pulses are sent in
+        for (from, ids) in targets.into_iter().enumerate() {
+            modules[from].outputs = ids
+                .into_iter()
+                .map(|to| {
+                    let input = modules[to].inputs.iter().position(|i| *i == from).unwrap();
+                    let bit = match modules[to].kind {
+                        Kind::Conjunction => modules[to].bits.start + input,
+                        _ => modules[to].bits.start,
+                    };
+                    (to, bit)
+                })
+                .collect();
+        }
+
+        Ok(Self {
+            modules,
+            broadcaster,
+            state_bits,
+        })
+    }
+}
+
+/// Number of button presses until `target` receives a low pulse, by pressing the button
+fn simulate_until_low(network: &Network, target: ModuleId, limit: usize) -> Option<usize> {
+    let mut state = network.initial_state();
+    (1..=limit).find(|_| {
+        let mut low = false;
+        network.press(&mut state, |pulse| {
+            low |= pulse.to == target && !pulse.high;
+            true
+        });
+        low
+    })
+}
+
+/// Value change dump of every module's output for `count` button presses, starting with press
+/// `first` (counted from 1). Modules that send nothing, like rx, show the last pulse they
+/// received. Each press takes one time step per step of the press, plus one.
+fn write_vcd(network: &Network, first: usize, count: usize) -> String {
+    let modules = &network.modules;
+    let identifiers: Vec<String> = (0..=modules.len()).map(vcd_identifier).collect();
+    let press_identifier = &identifiers[modules.len()];
+    // the modules whose signal a pulse sets; the button is no module
+    let signals_of = |pulse: &Pulse| {
+        let sender = (pulse.time > 0).then_some(pulse.from);
+        let receiver = (modules[pulse.to].kind == Kind::Output).then_some(pulse.to);
+        sender.into_iter().chain(receiver)
+    };
+
+    let mut vcd = String::new();
+    writeln!(vcd, "$version Advent of Code 2023 day 20 $end").unwrap();
+    writeln!(vcd, "$timescale 1ns $end").unwrap();
+    writeln!(vcd, "$scope module network $end").unwrap();
+    for (module, identifier) in modules.iter().zip(&identifiers) {
+        writeln!(vcd, "$var wire 1 {} {} $end", identifier, module.name).unwrap();
+    }
+    writeln!(
+        vcd,
+        "$var integer 32 {} button_press $end",
+        press_identifier
+    )
+    .unwrap();
+    writeln!(vcd, "$upscope $end").unwrap();
+    writeln!(vcd, "$enddefinitions $end").unwrap();
+
+    let mut state = network.initial_state();
+    let mut signals: Vec<Option<bool>> = vec![None; modules.len()];
+    for _ in 1..first {
+        network.press(&mut state, |pulse| {
+            signals_of(pulse).for_each(|module| signals[module] = Some(pulse.high));
+            true
+        });
+    }
+    writeln!(vcd, "#0").unwrap();
+    writeln!(vcd, "$dumpvars").unwrap();
+    for (value, identifier) in signals.iter().zip(&identifiers) {
+        let value = value.map_or('x', |high| if high { '1' } else { '0' });
+        writeln!(vcd, "{}{}", value, identifier).unwrap();
+    }
+    writeln!(vcd, "b{:b} {}", first, press_identifier).unwrap();
+    writeln!(vcd, "$end").unwrap();
+
+    let mut time = 0;
+    for press in first..first + count {
+        if press > first {
+            writeln!(vcd, "#{}", time).unwrap();
+            writeln!(vcd, "b{:b} {}", press, press_identifier).unwrap();
+        }
+        let mut changes = vec![];
+        network.press(&mut state, |pulse| {
+            changes.extend(signals_of(pulse).map(|module| (pulse.time, module, pulse.high)));
+            true
+        });
+        let mut current = None;
+        for &(step, module, high) in &changes {
+            if signals[module] == Some(high) {
+                continue;
+            }
+            if current != Some(step) {
+                writeln!(vcd, "#{}", time + step).unwrap();
+                current = Some(step);
+            }
+            writeln!(vcd, "{}{}", high as u8, identifiers[module]).unwrap();
+            signals[module] = Some(high);
+        }
+        time += changes.last().map_or(0, |c| c.0) + 1;
+    }
+    writeln!(vcd, "#{}", time).unwrap();
+    vcd
+}
+
+/// Short identifier made of printable characters, as used in value change dumps
+fn vcd_identifier(mut index: usize) -> String {
+    let mut identifier = String::new();
+    loop {
+        identifier.push((b'!' + (index % 94) as u8) as char);
+        index /= 94;
+        if index == 0 {
+            return identifier;
+        }
+    }
+}
+
+/// Set of presses `p` (counted from 0) with `p = residue (mod modulus)`
+type Congruences = Vec<(i128, i128)>;
+
+fn intersect(a: &Congruences, b: &Congruences) -> Congruences {
+    let mut result: Congruences = a
+        .iter()
+        .flat_map(|x| b.iter().filter_map(|y| math::crt([*x, *y])))
+        .collect();
+    result.sort_unstable();
+    result.dedup();
+    result
+}
+
+/// Part of the network behind one output of the broadcaster, which only receives pulses from the
+/// broadcaster and from itself. Its state after every press is followed until it repeats.
+struct Counter {
+    /// State bits of the modules in the counter
+    bits: Vec<usize>,
+    /// Modules of the counter that send pulses to the shared part of the network
+    lines: Vec<ModuleId>,
+    states: CycleStates<CounterState>,
+    /// Pulses sent on the lines, as `(line, high)`, by pressing the button in each of the states
+    traces: Vec<Vec<(usize, bool)>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CounterState {
+    modules: State,
+    /// Last pulse sent on each line, if any
+    lines: Vec<Option<bool>>,
+}
+
+impl Counter {
+    /// Presses after the start of the cycle in which `condition` holds for the press
+    fn presses_where<F>(&self, condition: F) -> Congruences
+    where
+        F: Fn(&CounterState, &[(usize, bool)]) -> bool,
+    {
+        let Cycle { start, length } = self.states.cycle;
+        (start..start + length)
+            .filter(|&press| condition(&self.states.states[press], &self.traces[press]))
+            .map(|press| ((press % length) as i128, length as i128))
+            .collect()
+    }
+}
+
+/// The network split into independent counters, which feed a loop free network of conjunctions
+/// shared between them. Every pulse rx receives comes from that shared part or directly from a
+/// counter.
+struct Decomposition<'a> {
+    network: &'a Network,
+    rx: ModuleId,
+    counters: Vec<Counter>,
+    /// Shared modules in topological order
+    shared: Vec<ModuleId>,
+    /// Counter and line index of every module sending pulses to the shared part
+    lines: Vec<Option<(usize, usize)>>,
+}
+
+impl<'a> Decomposition<'a> {
+    /// Splits the network and follows every counter until it repeats, or explains why that fails
+    fn new(network: &'a Network, rx: ModuleId, limit: usize) -> Result<Self, String> {
+        let modules = &network.modules;
+        let broadcaster = &modules[network.broadcaster];
+        if !broadcaster.inputs.is_empty() {
+            return Err(String::from("the broadcaster receives pulses"));
+        }
+
+        let entries: Vec<ModuleId> = broadcaster.outputs.iter().map(|(to, _)| *to).collect();
+        let mut owners = vec![vec![]; modules.len()];
+        for (index, entry) in entries.iter().enumerate() {
+            let mut queue = vec![*entry];
+            while let Some(id) = queue.pop() {
+                if owners[id].contains(&index) {
+                    continue;
+                }
+                owners[id].push(index);
+                queue.extend(modules[id].outputs.iter().map(|(to, _)| *to));
+            }
+        }
+        // None for modules shared between counters, and for rx
+        let owner = |id: ModuleId| match owners[id].as_slice() {
+            [] => None,
+            [index] if id != rx => Some(Some(*index)),
+            _ => Some(None),
+        };
+
+        let mut counter_modules = vec![vec![]; entries.len()];
+        let mut shared = vec![];
+        for (id, module) in modules.iter().enumerate() {
+            match owner(id) {
+                None => {}
+                Some(Some(index)) => {
+                    let outside = module.inputs.iter().find(|i| {
+                        **i != network.broadcaster && owner(**i).is_some_and(|o| o != Some(index))
+                    });
+                    if let Some(outside) = outside {
+                        return Err(format!(
+                            "{} receives pulses from {}, outside its counter",
+                            module.name, modules[*outside].name
+                        ));
+                    }
+                    counter_modules[index].push(id);
+                }
+                Some(None) => {
+                    if entries.contains(&id) {
+                        return Err(format!(
+                            "{} is reached from several broadcaster outputs",
+                            module.name
+                        ));
+                    }
+                    if !matches!(module.kind, Kind::Conjunction | Kind::Output) {
+                        return Err(format!(
+                            "{} is shared between counters, but not a conjunction",
+                            module.name
+                        ));
+                    }
+                    shared.push(id);
+                }
+            }
+        }
+        let shared =
+            topological_order(network, shared).ok_or("the shared conjunctions form a loop")?;
+        let mut is_shared = vec![false; modules.len()];
+        shared.iter().for_each(|id| is_shared[*id] = true);
+
+        let mut lines = vec![None; modules.len()];
+        let mut counters = vec![];
+        for (index, (entry, ids)) in entries.into_iter().zip(counter_modules).enumerate() {
+            let counter_lines: Vec<ModuleId> = ids
+                .iter()
+                .copied()
+                .filter(|id| modules[*id].outputs.iter().any(|(to, _)| is_shared[*to]))
+                .collect();
+            counter_lines
+                .iter()
+                .enumerate()
+                .for_each(|(line, id)| lines[*id] = Some((index, line)));
+            let bits: Vec<usize> = ids
+                .iter()
+                .flat_map(|id| modules[*id].bits.clone())
+                .collect();
+
+            let mut state = network.initial_state();
+            let initial = CounterState {
+                modules: state.gather(&bits),
+                lines: vec![None; counter_lines.len()],
+            };
+            let mut traces = vec![];
+            let start = Pulse {
+                from: network.broadcaster,
+                to: entry,
+                high: false,
+                time: 1,
+            };
+            let states = cycle::find_hashed_within(
+                initial,
+                |counter| {
+                    state.scatter(&bits, &counter.modules);
+                    let mut trace = vec![];
+                    let mut sent = counter.lines.clone();
+                    network.send(&mut state, vec![start], |pulse| {
+                        if !is_shared[pulse.to] {
+                            return true;
+                        }
+                        if let Some((_, line)) = lines[pulse.from] {
+                            trace.push((line, pulse.high));
+                            sent[line] = Some(pulse.high);
+                        }
+                        false
+                    });
+                    traces.push(trace);
+                    CounterState {
+                        modules: state.gather(&bits),
+                        lines: sent,
+                    }
+                },
+                limit,
+            )
+            .ok_or_else(|| {
+                format!(
+                    "the counter behind {} does not repeat within {limit} button presses",
+                    modules[entry].name
+                )
+            })?;
+            counters.push(Counter {
+                bits,
+                lines: counter_lines,
+                states,
+                traces,
+            });
+        }
+
+        Ok(Self {
+            network,
+            rx,
+            counters,
+            shared,
+            lines,
+        })
+    }
+
+    /// Presses in which `module` is high at some point, as far as the counters tell
+    fn high_presses(&self, module: ModuleId) -> Congruences {
+        match self.lines[module] {
+            Some((counter, line)) => self.counters[counter].presses_where(|state, trace| {
+                state.lines[line] == Some(true) || trace.contains(&(line, true))
+            }),
+            None => vec![(0, 1)],
+        }
+    }
+
+    /// Presses in which rx may receive a low pulse. A conjunction only sends a low pulse while all
+    /// its inputs are high, so every input has to be high at some point during the press.
+    fn candidate_presses(&self) -> Congruences {
+        let mut candidates = vec![];
+        for &input in &self.network.modules[self.rx].inputs {
+            let module = &self.network.modules[input];
+            if let Some((counter, line)) = self.lines[input] {
+                candidates.extend(
+                    self.counters[counter].presses_where(|_, trace| trace.contains(&(line, false))),
+                );
+            } else if module.kind == Kind::Conjunction && self.shared.contains(&input) {
+                candidates.extend(module.inputs.iter().fold(vec![(0, 1)], |presses, input| {
+                    intersect(&presses, &self.high_presses(*input))
+                }));
+            }
+        }
+        candidates.sort_unstable();
+        candidates.dedup();
+        candidates
+    }
+
+    /// Puts every module in the state it has before `press`, and checks whether rx receives a
+    /// low pulse when the button is pressed
+    fn sends_low_to_rx(&self, press: usize) -> bool {
+        let modules = &self.network.modules;
+        let mut state = self.network.initial_state();
+        let mut sent = vec![None; modules.len()];
+        for counter in &self.counters {
+            let counter_state = counter.states.state_at(press);
+            state.scatter(&counter.bits, &counter_state.modules);
+            counter
+                .lines
+                .iter()
+                .zip(&counter_state.lines)
+                .for_each(|(line, value)| sent[*line] = *value);
+        }
+        // the shared conjunctions remember the last pulse of each input, which follows from the
+        // last pulses sent by the counters
+        for &id in &self.shared {
+            let module = &modules[id];
+            if module.kind != Kind::Conjunction {
+                continue;
+            }
+            let mut received = false;
+            for (input, bit) in module.inputs.iter().zip(module.bits.clone()) {
+                received |= sent[*input].is_some();
+                state.set(bit, sent[*input].unwrap_or(false));
+            }
+            let output = !module.bits.clone().all(|bit| state.get(bit));
+            sent[id] = received.then_some(output);
+        }
+
+        let mut low_rx = false;
+        self.network.press(&mut state, |pulse| {
+            low_rx |= pulse.to == self.rx && !pulse.high;
+            true
+        });
+        low_rx
+    }
+
+    /// Number of button presses until rx receives a low pulse. Presses before every counter is in
+    /// its cycle are checked one by one, after that only the candidate presses are checked.
+    fn first_low_rx_pulse(&self, limit: usize) -> Result<usize, String> {
+        let settled = self
+            .counters
+            .iter()
+            .map(|c| c.states.cycle.start)
+            .max()
+            .unwrap_or(0);
+        if let Some(press) = (0..settled).find(|press| self.sends_low_to_rx(*press)) {
+            return Ok(press + 1);
+        }
+        let candidates = self.candidate_presses();
+        if candidates.is_empty() {
+            return Err(String::from("rx never receives a low pulse"));
+        }
+        let mut press = settled as i128;
+        for _ in 0..limit {
+            press = candidates
+                .iter()
+                .map(|(residue, modulus)| {
+                    residue + ((press - residue).max(0) + modulus - 1) / modulus * modulus
+                })
+                .min()
+                .unwrap();
+            if self.sends_low_to_rx(press as usize) {
+                return Ok(press as usize + 1);
+            }
+            press += 1;
+        }
+        Err(format!(
+            "rx did not receive a low pulse in any of the first {limit} candidate presses"
+        ))
+    }
+}
+
+/// Orders the modules so that each comes after the modules in the list sending pulses to it
+fn topological_order(network: &Network, ids: Vec<ModuleId>) -> Option<Vec<ModuleId>> {
+    let modules = &network.modules;
+    let mut incoming = vec![0; modules.len()];
+    ids.iter()
+        .flat_map(|id| modules[*id].outputs.iter())
+        .filter(|(to, _)| ids.contains(to))
+        .for_each(|(to, _)| incoming[*to] += 1);
+    let mut ready: Vec<ModuleId> = ids
+        .iter()
+        .copied()
+        .filter(|id| incoming[*id] == 0)
+        .collect();
+    let mut order = vec![];
+    while let Some(id) = ready.pop() {
+        order.push(id);
+        for (to, _) in &modules[id].outputs {
+            if ids.contains(to) {
+                incoming[*to] -= 1;
+                if incoming[*to] == 0 {
+                    ready.push(*to);
+                }
+            }
+        }
+    }
+    (order.len() == ids.len()).then_some(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{simulate_until_low, Network, Puzzle, Solution};
+    use common::{
+        cycle::{self, Cycle},
+        Answer,
+    };
+
+    const TEST_INPUT1: &str = "broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a";
+
+    const TEST_INPUT2: &str = "broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+
+    #[tokio::test]
+    async fn part_a() {
+        let mut puzzle = Puzzle::default();
+        assert_eq!(
+            puzzle.solve_a(String::from(TEST_INPUT1)),
+            Ok(Answer::from(32000000))
+        );
+        assert_eq!(
+            puzzle.solve_a(String::from(TEST_INPUT2)),
+            Ok(Answer::from(11687500))
+        );
+
+        // the second example is back in its initial state after four presses, the first one
+        // after every press
+        let network = TEST_INPUT2.parse::<Network>().unwrap();
+        let press = |state: &_| {
+            let mut next = Clone::clone(state);
+            network.press(&mut next, |_| true);
+            next
+        };
+        assert_eq!(
+            cycle::find_hashed(network.initial_state(), press).cycle,
+            Cycle {
+                start: 0,
+                length: 4
+            }
+        );
+        let network = TEST_INPUT1.parse::<Network>().unwrap();
+        let mut state = network.initial_state();
+        network.press(&mut state, |_| true);
+        assert_eq!(state, network.initial_state());
+    }
+
+    #[tokio::test]
+    async fn vcd_export() {
+        let mut puzzle = Puzzle {
+            vcd_presses: 2,
+            ..Puzzle::default()
+        };
+        let vcd = puzzle.export(String::from(TEST_INPUT2), "vcd").unwrap();
+        let (header, changes) = vcd.split_once("$enddefinitions $end\n").unwrap();
+        assert!(header.starts_with("$version Advent of Code 2023 day 20 $end\n"));
+        assert!(header.contains("$timescale 1ns $end\n$scope module network $end\n"));
+        assert!(header.ends_with("$upscope $end\n"));
+
+        let vars: Vec<Vec<&str>> = header
+            .lines()
+            .filter(|l| l.starts_with("$var "))
+            .map(|l| l.split(' ').collect())
+            .collect();
+        let names: Vec<&str> = vars.iter().map(|v| v[4]).collect();
+        assert_eq!(
+            names,
+            [
+                "broadcaster",
+                "a",
+                "inv",
+                "b",
+                "con",
+                "output",
+                "button_press"
+            ]
+        );
+        assert!(vars[..6].iter().all(|v| v[1] == "wire" && v[2] == "1"));
+        assert_eq!(vars[6][1..3], ["integer", "32"]);
+        let id = |name: &str| vars.iter().find(|v| v[4] == name).unwrap()[3];
+
+        // every module starts unknown, in the first press
+        let dump = changes
+            .split_once("$dumpvars\n")
+            .and_then(|(_, rest)| rest.split_once("$end\n"))
+            .unwrap()
+            .0;
+        assert_eq!(dump.lines().filter(|l| l.starts_with('x')).count(), 6);
+        assert!(dump.contains(&format!("b1 {}\n", id("button_press"))));
+
+        // a turns on in step 2 of the first press, which makes con and output high in step 3;
+        // the second press starts at time 6 and turns a off again
+        assert!(changes.contains(&format!("#2\n1{}\n", id("a"))));
+        assert!(changes.contains(&format!(
+            "#3\n0{}\n1{}\n1{}\n",
+            id("inv"),
+            id("con"),
+            id("output")
+        )));
+        assert!(changes.contains(&format!("#6\nb10 {}\n", id("button_press"))));
+        assert!(changes.contains(&format!("#8\n0{}\n", id("a"))));
+        assert!(changes.ends_with("#10\n"));
+
+        let mut puzzle = Puzzle {
+            vcd_first_press: 0,
+            ..Puzzle::default()
+        };
+        assert!(puzzle.export(String::from(TEST_INPUT2), "vcd").is_err());
+        assert!(puzzle.export(String::from(TEST_INPUT2), "fst").is_err());
+    }
+
+    const TEST_INPUT3: &str = "broadcaster -> no1l, no2l, no3l, no4l
+%no1l -> no1src
+%no2l -> no2src
+%no3l -> no3src
+%no4l -> no4src
+&no1src -> no1
+&no2src -> no2
+&no3src -> no3
+&no4src -> no4
+&no1 -> rxcon
+&no2 -> rxcon
+&no3 -> rxcon
+&no4 -> rxcon
+&rxcon -> rx";
+
+    /// Binary counters built from flip-flops like the real inputs, each resetting when it reaches
+    /// its target; rx receives a low pulse once all of them reset in the same press
+    fn counters(targets: &[usize]) -> String {
+        let mut lines = vec![];
+        let mut entries = vec![];
+        for (c, target) in targets.iter().enumerate() {
+            let bits = usize::BITS - target.leading_zeros();
+            let mut resets = vec![];
+            for bit in 0..bits {
+                let mut outputs = vec![];
+                if bit + 1 < bits {
+                    outputs.push(format!("c{c}b{}", bit + 1));
+                }
+                if target >> bit & 1 == 1 {
+                    outputs.push(format!("c{c}"));
+                }
+                if bit == 0 || target >> bit & 1 == 0 {
+                    resets.push(format!("c{c}b{bit}"));
+                }
+                lines.push(format!("%c{c}b{bit} -> {}", outputs.join(", ")));
+            }
+            resets.push(format!("i{c}"));
+            lines.push(format!("&c{c} -> {}", resets.join(", ")));
+            lines.push(format!("&i{c} -> fin"));
+            entries.push(format!("c{c}b0"));
+        }
+        lines.push(format!("broadcaster -> {}", entries.join(", ")));
+        lines.push(String::from("&fin -> rx"));
+        lines.join("\n")
+    }
+
+    #[tokio::test]
+    async fn part_b() {
+        let mut puzzle = Puzzle::default();
+        assert_eq!(
+            puzzle.solve_b(String::from(TEST_INPUT3)),
+            Ok(Answer::from(1))
+        );
+        let small = counters(&[11, 13, 7, 5]);
+        let network = small.parse::<Network>().unwrap();
+        let rx = network.id("rx").unwrap();
+        assert_eq!(simulate_until_low(&network, rx, 10_000), Some(5005));
+        assert_eq!(puzzle.solve_b(small), Ok(Answer::from(5005)));
+        assert_eq!(
+            puzzle.solve_b(counters(&[3917, 4051, 3733, 3769])),
+            Ok(Answer::from(3917_usize * 4051 * 3733 * 3769))
+        );
+
+        // the shared flip-flop is no conjunction, so this is only simulated
+        let shared_flip_flop = "broadcaster -> a, b\n%a -> c\n%b -> c\n%c -> rx";
+        assert_eq!(
+            puzzle.solve_b(String::from(shared_flip_flop)),
+            Ok(Answer::from(2))
+        );
+        puzzle.press_limit = 1;
+        let error = puzzle.solve_b(String::from(shared_flip_flop)).unwrap_err();
+        assert!(error.contains("c is shared between counters"));
+    }
+}
@@ -0,0 +1,467 @@
+#[cfg(feature = "performance")]
+use ahash::AHashSet as HashSet;
+#[cfg(not(feature = "performance"))]
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+use super::{Parameter, ParameterField, PuzzleInfo, Registration, Solution};
+use common::{math, Answer, Grid};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+pub struct Puzzle {
+    steps_a: usize,
+    steps_b: usize,
+}
+
+impl Default for Puzzle {
+    fn default() -> Self {
+        Self {
+            steps_a: 64,
+            steps_b: 26501365,
+        }
+    }
+}
+
+type Coord = (usize, usize);
+
+pub(super) const REGISTRATION: Registration = Registration::new::<Puzzle>(PuzzleInfo {
+    year: 2023,
+    day: 21,
+    title: "Step Counter",
+    has_visualization: false,
+    parameters: &[
+        Parameter {
+            name: "steps_a",
+            description: "Number of steps taken in part A",
+        },
+        Parameter {
+            name: "steps_b",
+            description: "Number of steps taken in part B",
+        },
+    ],
+    assumptions: &[
+        "Shortest paths in the tiled garden grow by one garden size per tile, from three tiles \
+         away from the start",
+    ],
+    exports: &[],
+});
+
+impl Solution for Puzzle {
+    fn solve_a(&mut self, input: String) -> Result<Answer, String> {
+        let (grid, steps) = parse_input(&input)?;
+        Answer::from(run_steps(&grid, steps, self.steps_a).len()).into()
+    }
+
+    fn solve_b(&mut self, input: String) -> Result<Answer, String> {
+        let (grid, mut steps) = parse_input(&input)?;
+        let start = *steps.iter().next().unwrap();
+        if !has_open_center_and_edges(&grid, start, self.steps_b) {
+            return Answer::from(count_tiled(&grid, start, self.steps_b)?).into();
+        }
+
+        // Basic scale of end result
+        let size = grid.height();
+        let total_width = (self.steps_b / size) - 1;
+
+        // Determine number of full grids
+        let even_grids = (total_width.div_ceil(2) * 2).pow(2);
+        steps = run_steps_until_corner(&grid, steps, size * 2);
+        let points_per_even_grid = steps.len();
+        let odd_grids = (total_width / 2 * 2 + 1).pow(2);
+        steps = make_step(&grid, &steps);
+        let points_per_odd_grid = steps.len();
+
+        let mut full_grid_steps =
+            even_grids * points_per_even_grid + odd_grids * points_per_odd_grid;
+
+        let start = 0;
+        let center = size / 2;
+        let end = size - 1;
+
+        // straight edges
+        let no_steps = size - 1;
+        full_grid_steps += [
+            (start, center),
+            (end, center),
+            (center, end),
+            (center, start),
+        ]
+        .into_par_iter()
+        .map(|start| run_steps(&grid, HashSet::from([start]), no_steps).len())
+        .sum::<usize>();
+
+        // all diagonal corners
+        let diagonal_count = total_width + 1;
+        let no_steps_small = size / 2 - 1;
+        let no_steps_large = ((size * 3) / 2 - 1) - no_steps_small;
+        full_grid_steps += [(start, end), (start, start), (end, start), (end, end)]
+            .into_par_iter()
+            .map(|start| {
+                let small_diagonal_steps = run_steps(&grid, HashSet::from([start]), no_steps_small);
+                let small_diagonal_step_count = small_diagonal_steps.len();
+                let large_steps = run_steps(&grid, small_diagonal_steps, no_steps_large).len();
+                diagonal_count * small_diagonal_step_count + large_steps * total_width
+            })
+            .sum::<usize>();
+
+        Answer::from(full_grid_steps).into()
+    }
+
+    fn parameters(&mut self) -> Vec<ParameterField<'_>> {
+        vec![
+            ParameterField::new_unsigned("steps_a", &mut self.steps_a),
+            ParameterField::new_unsigned("steps_b", &mut self.steps_b),
+        ]
+    }
+
+    #[cfg(feature = "ui")]
+    fn get_shapes(
+        &mut self,
+        _input: String,
+        _request: ui_support::DisplayRequest,
+    ) -> Option<ui_support::DisplayResult> {
+        None
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Grid<Cell>, HashSet<Coord>), String> {
+    let grid = Grid::parse(input, |c| c)?;
+    let start = grid.find(|c| *c == 'S').ok_or("No start found")?;
+    Ok((grid.map(|c| Cell::from(*c)), HashSet::from([start])))
+}
+
+/// Whether the fast path of part B applies: a square, odd sized garden with the start in the
+/// center, clear straight lines from the start to the edges and clear edges, and no plot that
+/// needs a detour to reach from the start or a corner. The steps have to end on the edge of a
+/// tile, an even number of gardens away from the start tile.
+fn has_open_center_and_edges(grid: &Grid<Cell>, start: Coord, steps: usize) -> bool {
+    let size = grid.width();
+    let is_open = |cell: &Cell| *cell == Cell::Garden;
+    let end = size.saturating_sub(1);
+    grid.height() == size
+        && size % 2 == 1
+        && start == (size / 2, size / 2)
+        && steps / size > 1
+        && (steps / size).is_multiple_of(2)
+        && steps % size == size / 2
+        && [0, start.1, end]
+            .into_iter()
+            .all(|y| grid.row(y).iter().all(is_open))
+        && [0, start.0, end]
+            .into_iter()
+            .all(|x| grid.column(x).all(is_open))
+        && [start, (0, 0), (end, 0), (0, end), (end, end)]
+            .into_iter()
+            .all(|from| has_direct_paths(grid, from))
+}
+
+/// Whether every reachable plot of a single tile is as far from `from` as its Manhattan distance
+fn has_direct_paths(grid: &Grid<Cell>, from: Coord) -> bool {
+    let distances = TileDistances::new(grid, from, 0);
+    grid.iter().all(|(pos, _)| {
+        distances
+            .get((0, 0), pos)
+            .is_none_or(|d| d == pos.0.abs_diff(from.0) + pos.1.abs_diff(from.1))
+    })
+}
+
+/// Counts the plots reachable in exactly `steps` steps in the infinitely tiled garden, for any
+/// garden and start.
+///
+/// A plot is reachable when its distance has the parity of `steps` and is not larger. Distances
+/// are taken from a breadth first search over a block of tiles around the start. From
+/// `PERIODIC_RADIUS` tiles out, every tile to the side adds the garden width to the distances of
+/// the tile before it, and every tile above or below adds the height, which is verified one tile
+/// further. The tiles beyond are counted per plot with that pattern.
+fn count_tiled(grid: &Grid<Cell>, start: Coord, steps: usize) -> Result<usize, String> {
+    let distances = TileDistances::new(grid, start, PERIODIC_RADIUS + 1);
+    if !distances.is_periodic(PERIODIC_RADIUS) {
+        return Err(format!(
+            "Distances in the tiled garden are not periodic {} tiles away from the start",
+            PERIODIC_RADIUS
+        ));
+    }
+    Ok(distances.count(PERIODIC_RADIUS, steps))
+}
+
+/// Tiles away from the start tile from which distances grow by one garden size per tile
+const PERIODIC_RADIUS: isize = 3;
+
+/// Marks plots that are not reached in `TileDistances`
+const UNREACHED: u32 = u32::MAX;
+
+/// Distance from the start to every plot in a square block of tiles centered on the start tile
+struct TileDistances<'a> {
+    grid: &'a Grid<Cell>,
+    radius: isize,
+    width: usize,
+    distances: Vec<u32>,
+}
+
+impl<'a> TileDistances<'a> {
+    fn new(grid: &'a Grid<Cell>, start: Coord, radius: isize) -> Self {
+        let tiles = 2 * radius as usize + 1;
+        let (width, height) = (tiles * grid.width(), tiles * grid.height());
+        let mut distances = vec![UNREACHED; width * height];
+        let start = (
+            radius as usize * grid.width() + start.0,
+            radius as usize * grid.height() + start.1,
+        );
+        distances[start.1 * width + start.0] = 0;
+        let mut queue = VecDeque::from([(start, 0)]);
+        while let Some(((x, y), distance)) = queue.pop_front() {
+            let neighbors = [
+                (x.wrapping_sub(1), y),
+                (x + 1, y),
+                (x, y.wrapping_sub(1)),
+                (x, y + 1),
+            ];
+            for (nx, ny) in neighbors {
+                if nx >= width || ny >= height {
+                    continue;
+                }
+                let index = ny * width + nx;
+                let cell = &grid[(nx % grid.width(), ny % grid.height())];
+                if *cell == Cell::Garden && distances[index] == UNREACHED {
+                    distances[index] = distance + 1;
+                    queue.push_back(((nx, ny), distance + 1));
+                }
+            }
+        }
+        Self {
+            grid,
+            radius,
+            width,
+            distances,
+        }
+    }
+
+    /// Distance to a plot of the tile `tile` steps away from the start tile
+    fn get(&self, tile: (isize, isize), (x, y): Coord) -> Option<usize> {
+        let x = (tile.0 + self.radius) as usize * self.grid.width() + x;
+        let y = (tile.1 + self.radius) as usize * self.grid.height() + y;
+        let distance = self.distances[y * self.width + x];
+        (distance != UNREACHED).then_some(distance as usize)
+    }
+
+    /// Whether the tiles `radius` steps away from the start tile are one garden size closer than
+    /// their outer neighbors, for every plot
+    fn is_periodic(&self, radius: isize) -> bool {
+        let (width, height) = (self.grid.width(), self.grid.height());
+        let ring = (-radius..=radius).flat_map(|other| {
+            [
+                ((radius, other), (1, 0), width),
+                ((-radius, other), (-1, 0), width),
+                ((other, radius), (0, 1), height),
+                ((other, -radius), (0, -1), height),
+            ]
+        });
+        ring.into_iter()
+            .flat_map(|(tile, (dx, dy), size)| {
+                let outer = (tile.0 + dx, tile.1 + dy);
+                self.grid
+                    .iter()
+                    .map(move |(pos, _)| (self.get(tile, pos), self.get(outer, pos), size))
+            })
+            .all(|(inner, outer, size)| inner.map(|d| d + size) == outer)
+    }
+
+    fn count(&self, radius: isize, steps: usize) -> usize {
+        let (width, height) = (self.grid.width() as i128, self.grid.height() as i128);
+        let mut count = 0;
+        for tile_y in -radius..=radius {
+            for tile_x in -radius..=radius {
+                let edge = (tile_x.abs() == radius, tile_y.abs() == radius);
+                for (pos, _) in self.grid.iter() {
+                    let Some(distance) = self.get((tile_x, tile_y), pos) else {
+                        continue;
+                    };
+                    let remaining = steps as i128 - distance as i128;
+                    count += match edge {
+                        (false, false) => (remaining >= 0 && remaining % 2 == 0) as i128,
+                        (true, false) => count_in_line(remaining, width),
+                        (false, true) => count_in_line(remaining, height),
+                        (true, true) => count_in_corner(remaining, width, height),
+                    };
+                }
+            }
+        }
+        count as usize
+    }
+}
+
+/// Number of `k >= 0` for which a plot `k * size` steps further is reached with `remaining`
+/// steps left, matching its parity
+fn count_in_line(remaining: i128, size: i128) -> i128 {
+    if remaining < 0 {
+        return 0;
+    }
+    (0..2)
+        .filter(|k| (k * size - remaining) % 2 == 0 && k * size <= remaining)
+        .map(|k| (remaining - k * size) / (2 * size) + 1)
+        .sum()
+}
+
+/// Number of `(i, j) >= 0` for which a plot `i * width + j * height` steps further is reached
+/// with `remaining` steps left, matching its parity
+fn count_in_corner(remaining: i128, width: i128, height: i128) -> i128 {
+    if remaining < 0 {
+        return 0;
+    }
+    // split i by parity, `i = 2u + k`, so the parity j needs is fixed per k
+    (0..2)
+        .filter(|k| k * width <= remaining)
+        .map(|k| {
+            let left = remaining - k * width;
+            let last = left / (2 * width);
+            if height % 2 == 0 {
+                if left % 2 != 0 {
+                    return 0;
+                }
+                math::floor_sum(last + 1, height, 2 * width, left - 2 * width * last) + last + 1
+            } else {
+                // count of `j <= J` with the parity of `left` is `floor((J - parity + 2) / 2)`
+                let offset = (2 - left % 2) * height;
+                math::floor_sum(
+                    last + 1,
+                    2 * height,
+                    2 * width,
+                    left - 2 * width * last + offset,
+                )
+            }
+        })
+        .sum()
+}
+
+/// Not sure if this works on every input, but it saves ~100ms
+fn run_steps_until_corner(
+    grid: &Grid<Cell>,
+    mut steps: HashSet<Coord>,
+    n: usize,
+) -> HashSet<Coord> {
+    for _ in 0..n {
+        steps = make_step(grid, &steps);
+        if steps.contains(&(0, 0)) {
+            break;
+        }
+    }
+    steps
+}
+
+fn run_steps(grid: &Grid<Cell>, mut steps: HashSet<Coord>, n: usize) -> HashSet<Coord> {
+    for _ in 0..n {
+        steps = make_step(grid, &steps);
+    }
+    steps
+}
+
+fn make_step(grid: &Grid<Cell>, steps: &HashSet<Coord>) -> HashSet<Coord> {
+    let mut next = HashSet::new();
+    for pos in steps {
+        next.extend(
+            grid.neighbors4(*pos)
+                .filter(|neighbor| grid[*neighbor] == Cell::Garden),
+        );
+    }
+    next
+}
+
+#[derive(PartialEq)]
+enum Cell {
+    Garden,
+    Rock,
+}
+
+impl From<char> for Cell {
+    fn from(c: char) -> Self {
+        match c {
+            '.' | 'S' => Cell::Garden,
+            '#' => Cell::Rock,
+            _ => panic!("Invalid cell"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use super::{count_tiled, has_open_center_and_edges, parse_input, Puzzle};
+    use common::Answer;
+
+    const TEST_INPUT: &str = "...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+
+    #[tokio::test]
+    async fn part_a() {
+        let mut puzzle = Puzzle {
+            steps_a: 6,
+            ..Default::default()
+        };
+        assert_eq!(
+            puzzle.solve_a(String::from(TEST_INPUT)),
+            Ok(Answer::from(16))
+        )
+    }
+
+    /// Garden like the puzzle input: open center row, column and edges, and only scattered rocks
+    const DIAMOND_INPUT: &str = "...........
+...........
+..#........
+.......#...
+...........
+.....S.....
+........#..
+...#.......
+...........
+.#......#..
+...........";
+
+    #[tokio::test]
+    async fn part_b() {
+        for (steps, plots) in [
+            (6, 16),
+            (10, 50),
+            (50, 1594),
+            (100, 6536),
+            (500, 167004),
+            (1000, 668697),
+            (5000, 16733044),
+        ] {
+            let mut puzzle = Puzzle {
+                steps_b: steps,
+                ..Default::default()
+            };
+            assert_eq!(
+                puzzle.solve_b(String::from(TEST_INPUT)),
+                Ok(Answer::from(plots))
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn diamond_fast_path() {
+        let (grid, steps) = parse_input(DIAMOND_INPUT).unwrap();
+        let start = *steps.iter().next().unwrap();
+        for tiles in [2, 4, 6, 10, 2024] {
+            let steps = 11 * tiles + 5;
+            assert!(has_open_center_and_edges(&grid, start, steps));
+            let mut puzzle = Puzzle {
+                steps_b: steps,
+                ..Default::default()
+            };
+            assert_eq!(
+                puzzle.solve_b(String::from(DIAMOND_INPUT)),
+                Ok(Answer::from(count_tiled(&grid, start, steps).unwrap()))
+            );
+        }
+    }
+}
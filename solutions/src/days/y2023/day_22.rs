@@ -0,0 +1,458 @@
+use std::fmt::{Debug, Display, Write};
+
+use super::{Export, PuzzleInfo, Registration, Solution};
+use common::{
+    geom::Point3,
+    interval::InclusiveInterval,
+    parse::{self, ParseError},
+    Answer,
+};
+
+#[derive(Default)]
+pub struct Puzzle;
+
+pub(super) const REGISTRATION: Registration = Registration::new::<Puzzle>(PuzzleInfo {
+    year: 2023,
+    day: 22,
+    title: "Sand Slabs",
+    has_visualization: false,
+    parameters: &[],
+    assumptions: &[],
+    exports: &[
+        Export {
+            name: "obj",
+            description: "Settled stack as Wavefront OBJ, grouped and colored by whether \
+                          bricks are safe to disintegrate",
+        },
+        Export {
+            name: "stl",
+            description: "Settled stack as ASCII STL, with one solid for the bricks that are \
+                          safe to disintegrate and one for the others",
+        },
+        Export {
+            name: "falling_obj",
+            description: "Bricks as given in the input as Wavefront OBJ, grouped like the \
+                          settled stack",
+        },
+        Export {
+            name: "falling_stl",
+            description: "Bricks as given in the input as ASCII STL, split like the settled stack",
+        },
+    ],
+});
+
+impl Solution for Puzzle {
+    fn solve_a(&mut self, input: String) -> Result<Answer, String> {
+        let stack = Stack::settle(parse_input(&input)?);
+        let result = (0..stack.bricks.len())
+            .filter(|b| stack.is_safe_to_disintegrate(*b))
+            .count();
+        Answer::from(result).into()
+    }
+
+    fn solve_b(&mut self, input: String) -> Result<Answer, String> {
+        let stack = Stack::settle(parse_input(&input)?);
+        let result: usize = stack.falling_counts().into_iter().sum();
+        Answer::from(result).into()
+    }
+
+    fn export(&mut self, input: String, name: &str) -> Result<String, String> {
+        let bricks = parse_input(&input)?;
+        let stack = Stack::settle(bricks.clone());
+        let safe: Vec<bool> = (0..bricks.len())
+            .map(|b| stack.is_safe_to_disintegrate(b))
+            .collect();
+        match name {
+            "obj" => Ok(write_obj(&stack.bricks, &safe)),
+            "stl" => Ok(write_stl(&stack.bricks, &safe)),
+            "falling_obj" => Ok(write_obj(&bricks, &safe)),
+            "falling_stl" => Ok(write_stl(&bricks, &safe)),
+            _ => Err(format!("Unknown export: {}", name)),
+        }
+    }
+
+    #[cfg(feature = "ui")]
+    fn get_shapes(
+        &mut self,
+        _input: String,
+        _request: ui_support::DisplayRequest,
+    ) -> Option<ui_support::DisplayResult> {
+        None
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Brick>, ParseError> {
+    parse::lines(input)
+        .filter(|line| !line.text.trim().is_empty())
+        .map(|line| {
+            let (left, right) = line.split_once("~")?;
+            Ok(Brick::new(left.parse()?, right.parse()?))
+        })
+        .collect()
+}
+
+/// Corners of a box in counter clockwise order seen from outside, for each face with its normal.
+/// Bits 0, 1 and 2 of a corner select the high x, y and z side of the box.
+const BOX_FACES: [([usize; 4], [i32; 3]); 6] = [
+    ([0, 2, 3, 1], [0, 0, -1]),
+    ([4, 5, 7, 6], [0, 0, 1]),
+    ([0, 1, 5, 4], [0, -1, 0]),
+    ([2, 6, 7, 3], [0, 1, 0]),
+    ([0, 4, 6, 2], [-1, 0, 0]),
+    ([1, 3, 7, 5], [1, 0, 0]),
+];
+
+/// Wavefront OBJ with an object per brick, in group `safe` or `load_bearing`. The vertices carry
+/// colors as well, which most viewers show: green for safe bricks, red for the others.
+fn write_obj(bricks: &[Brick], safe: &[bool]) -> String {
+    let mut obj = String::new();
+    writeln!(obj, "# Advent of Code 2023 day 22, one unit per cube").unwrap();
+    for (index, brick) in bricks.iter().enumerate() {
+        let (group, color) = if safe[index] {
+            ("safe", "0.2 0.7 0.2")
+        } else {
+            ("load_bearing", "0.8 0.2 0.2")
+        };
+        writeln!(obj, "o brick_{}", index).unwrap();
+        writeln!(obj, "g {}", group).unwrap();
+        for corner in brick.corners() {
+            writeln!(obj, "v {} {} {} {}", corner.x, corner.y, corner.z, color).unwrap();
+        }
+        // OBJ counts vertices from 1, over the whole file
+        let first = index * 8 + 1;
+        for (corners, _) in BOX_FACES {
+            let [a, b, c, d] = corners.map(|c| first + c);
+            writeln!(obj, "f {} {} {} {}", a, b, c, d).unwrap();
+        }
+    }
+    obj
+}
+
+/// ASCII STL with a solid `safe` and a solid `load_bearing`, as STL has no colors
+fn write_stl(bricks: &[Brick], safe: &[bool]) -> String {
+    let mut stl = String::new();
+    for (name, is_safe) in [("safe", true), ("load_bearing", false)] {
+        writeln!(stl, "solid {}", name).unwrap();
+        for (_, brick) in bricks
+            .iter()
+            .enumerate()
+            .filter(|(b, _)| safe[*b] == is_safe)
+        {
+            let corners = brick.corners();
+            for (face, [nx, ny, nz]) in BOX_FACES {
+                for triangle in [[face[0], face[1], face[2]], [face[0], face[2], face[3]]] {
+                    writeln!(stl, "  facet normal {} {} {}", nx, ny, nz).unwrap();
+                    writeln!(stl, "    outer loop").unwrap();
+                    for corner in triangle.map(|c| corners[c]) {
+                        writeln!(stl, "      vertex {} {} {}", corner.x, corner.y, corner.z)
+                            .unwrap();
+                    }
+                    writeln!(stl, "    endloop").unwrap();
+                    writeln!(stl, "  endfacet").unwrap();
+                }
+            }
+        }
+        writeln!(stl, "endsolid {}", name).unwrap();
+    }
+    stl
+}
+
+/// Bricks after falling down, with the bricks each one rests on and carries
+struct Stack {
+    bricks: Vec<Brick>,
+    supported_by: Vec<Vec<usize>>,
+    supporting: Vec<Vec<usize>>,
+    /// Bricks from bottom to top; every brick comes after the bricks it rests on
+    order: Vec<usize>,
+}
+
+impl Stack {
+    /// Drops the bricks from low to high, keeping track of the top of the stack in every column
+    fn settle(mut bricks: Vec<Brick>) -> Self {
+        let mut order: Vec<usize> = (0..bricks.len()).collect();
+        order.sort_by_key(|b| bricks[*b].z.start);
+        let width = bricks.iter().map(|b| b.x.end + 1).max().unwrap_or(0);
+        let depth = bricks.iter().map(|b| b.y.end + 1).max().unwrap_or(0);
+        // height of the stack and the brick on top, per column
+        let mut tops: Vec<(usize, Option<usize>)> = vec![(0, None); width * depth];
+
+        let mut supported_by = vec![vec![]; bricks.len()];
+        let mut supporting = vec![vec![]; bricks.len()];
+        for &index in &order {
+            let brick = &mut bricks[index];
+            let columns: Vec<usize> = (brick.y.start..=brick.y.end)
+                .flat_map(|y| (brick.x.start..=brick.x.end).map(move |x| y * width + x))
+                .collect();
+            let height = columns.iter().map(|c| tops[*c].0).max().unwrap_or(0);
+            let mut supports: Vec<usize> = columns
+                .iter()
+                .filter(|c| tops[**c].0 == height)
+                .filter_map(|c| tops[*c].1)
+                .collect();
+            supports.sort_unstable();
+            supports.dedup();
+
+            brick.drop_to(height + 1);
+            columns
+                .iter()
+                .for_each(|c| tops[*c] = (brick.z.end, Some(index)));
+            supports.iter().for_each(|s| supporting[*s].push(index));
+            supported_by[index] = supports;
+        }
+        Self {
+            bricks,
+            supported_by,
+            supporting,
+            order,
+        }
+    }
+
+    /// No brick falls when this one is removed
+    fn is_safe_to_disintegrate(&self, brick: usize) -> bool {
+        self.supporting[brick]
+            .iter()
+            .all(|top| self.supported_by[*top].len() > 1)
+    }
+
+    /// Number of other bricks that fall when each brick is removed.
+    ///
+    /// A brick falls when every path of supports from the ground to it passes the removed brick,
+    /// so these are the bricks the removed brick dominates in the support graph. Walking from
+    /// bottom to top, the immediate dominator of a brick is the lowest common ancestor of its
+    /// supports in the dominator tree built so far.
+    fn falling_counts(&self) -> Vec<usize> {
+        let ground = self.bricks.len();
+        let mut tree = DominatorTree::new(ground);
+        for &brick in &self.order {
+            let dominator = self.supported_by[brick]
+                .iter()
+                .copied()
+                .reduce(|a, b| tree.lowest_common_ancestor(a, b))
+                .unwrap_or(ground);
+            tree.insert(brick, dominator);
+        }
+        let mut counts = vec![0; self.bricks.len()];
+        for &brick in self.order.iter().rev() {
+            let dominator = tree.parent(brick);
+            if dominator != ground {
+                counts[dominator] += counts[brick] + 1;
+            }
+        }
+        counts
+    }
+}
+
+/// Tree which grows from the root, with lowest common ancestors by binary lifting
+struct DominatorTree {
+    depth: Vec<usize>,
+    /// The ancestors `1 << i` levels up of every node, as far as they exist
+    ancestors: Vec<Vec<usize>>,
+}
+
+impl DominatorTree {
+    /// A tree with only the root; the nodes are `0..=root`
+    fn new(root: usize) -> Self {
+        Self {
+            depth: vec![0; root + 1],
+            ancestors: vec![vec![]; root + 1],
+        }
+    }
+
+    fn parent(&self, node: usize) -> usize {
+        self.ancestors[node][0]
+    }
+
+    fn insert(&mut self, node: usize, parent: usize) {
+        self.depth[node] = self.depth[parent] + 1;
+        let mut ancestors = vec![parent];
+        while let Some(next) = self.ancestors[*ancestors.last().unwrap()].get(ancestors.len() - 1) {
+            ancestors.push(*next);
+        }
+        self.ancestors[node] = ancestors;
+    }
+
+    fn lowest_common_ancestor(&self, mut a: usize, mut b: usize) -> usize {
+        if self.depth[a] < self.depth[b] {
+            (a, b) = (b, a);
+        }
+        let mut difference = self.depth[a] - self.depth[b];
+        let mut level = 0;
+        while difference > 0 {
+            if difference & 1 == 1 {
+                a = self.ancestors[a][level];
+            }
+            difference >>= 1;
+            level += 1;
+        }
+        if a == b {
+            return a;
+        }
+        // a and b stay at the same depth, so they have the same number of ancestor levels, which
+        // may shrink with every jump
+        for level in (0..self.ancestors[a].len()).rev() {
+            if level < self.ancestors[a].len()
+                && self.ancestors[a][level] != self.ancestors[b][level]
+            {
+                a = self.ancestors[a][level];
+                b = self.ancestors[b][level];
+            }
+        }
+        self.parent(a)
+    }
+}
+
+#[derive(Clone)]
+struct Brick {
+    x: InclusiveInterval<usize>,
+    y: InclusiveInterval<usize>,
+    z: InclusiveInterval<usize>,
+}
+
+impl Brick {
+    fn new(a: Point3<usize>, b: Point3<usize>) -> Self {
+        Self {
+            x: InclusiveInterval::new(a.x.min(b.x), a.x.max(b.x)),
+            y: InclusiveInterval::new(a.y.min(b.y), a.y.max(b.y)),
+            z: InclusiveInterval::new(a.z.min(b.z), a.z.max(b.z)),
+        }
+    }
+
+    /// Corners of the space the brick's cubes take up, indexed like in `BOX_FACES`
+    fn corners(&self) -> [Point3<usize>; 8] {
+        std::array::from_fn(|corner| Point3 {
+            x: if corner & 1 == 0 {
+                self.x.start
+            } else {
+                self.x.end + 1
+            },
+            y: if corner & 2 == 0 {
+                self.y.start
+            } else {
+                self.y.end + 1
+            },
+            z: if corner & 4 == 0 {
+                self.z.start
+            } else {
+                self.z.end + 1
+            },
+        })
+    }
+
+    /// Moves the brick down, so its bottom is at `z`
+    fn drop_to(&mut self, z: usize) {
+        self.z = InclusiveInterval::new(z, z + self.z.end - self.z.start);
+    }
+}
+
+impl Debug for Brick {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_string())
+    }
+}
+
+impl Display for Brick {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{}~{},{},{}",
+            self.x.start, self.y.start, self.z.start, self.x.end, self.y.end, self.z.end
+        )
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::default_constructed_unit_structs)]
+mod tests {
+    use super::Puzzle;
+    use super::Solution;
+    use common::Answer;
+
+    const TEST_INPUT: &str = "1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9";
+
+    #[tokio::test]
+    async fn part_a() {
+        let mut puzzle = Puzzle::default();
+        assert_eq!(
+            puzzle.solve_a(String::from(TEST_INPUT)),
+            Ok(Answer::from(5))
+        )
+    }
+
+    /// Corners of the object `brick_{index}` in an OBJ export, as written
+    fn obj_corners(obj: &str, index: usize) -> Vec<[i64; 3]> {
+        obj.split("\no ")
+            .find(|object| object.starts_with(&format!("brick_{}\n", index)))
+            .unwrap()
+            .lines()
+            .filter_map(|l| l.strip_prefix("v "))
+            .map(|v| {
+                let values: Vec<i64> = v.split(' ').take(3).map(|n| n.parse().unwrap()).collect();
+                [values[0], values[1], values[2]]
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn mesh_export() {
+        let mut puzzle = Puzzle::default();
+        let bricks = TEST_INPUT.lines().count();
+        for name in ["obj", "falling_obj"] {
+            let obj = puzzle.export(String::from(TEST_INPUT), name).unwrap();
+            let count = |prefix: &str| obj.lines().filter(|l| l.starts_with(prefix)).count();
+            assert_eq!(count("o "), bricks);
+            assert_eq!(count("v "), bricks * 8);
+            assert_eq!(count("f "), bricks * 6);
+            assert_eq!(count("g safe"), 5);
+            assert_eq!(count("g load_bearing"), 2);
+        }
+
+        // the top brick, 1,1,8~1,1,9, falls onto the brick at height 4 and then spans 5 to 6
+        let settled = puzzle.export(String::from(TEST_INPUT), "obj").unwrap();
+        let falling = puzzle
+            .export(String::from(TEST_INPUT), "falling_obj")
+            .unwrap();
+        for (obj, bottom, top) in [(settled, 5, 7), (falling, 8, 10)] {
+            let corners = obj_corners(&obj, 6);
+            assert_eq!(corners.len(), 8);
+            for [x, y, z] in corners {
+                assert!([1, 2].contains(&x) && [1, 2].contains(&y));
+                assert!(z == bottom || z == top);
+            }
+        }
+
+        for name in ["stl", "falling_stl"] {
+            let stl = puzzle.export(String::from(TEST_INPUT), name).unwrap();
+            let count = |prefix: &str| {
+                stl.lines()
+                    .filter(|l| l.trim_start().starts_with(prefix))
+                    .count()
+            };
+            assert_eq!(count("facet normal"), 12 * bricks);
+            assert_eq!(count("vertex"), 3 * 12 * bricks);
+            assert_eq!(count("solid "), 2);
+            assert_eq!(count("endsolid "), 2);
+        }
+        assert!(puzzle.export(String::from(TEST_INPUT), "ply").is_err());
+    }
+
+    #[tokio::test]
+    async fn part_b() {
+        let mut puzzle = Puzzle::default();
+        assert_eq!(
+            puzzle.solve_b(String::from(TEST_INPUT)),
+            Ok(Answer::from(7))
+        );
+        // every brick of a single tower carries all bricks above it
+        let tower = (1..=5000)
+            .map(|z| format!("0,0,{z}~0,0,{z}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(puzzle.solve_b(tower), Ok(Answer::from(5000 * 4999 / 2)));
+    }
+}
@@ -0,0 +1,703 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Write},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use super::{Export, PuzzleInfo, Registration, Solution};
+use common::{Answer, Grid};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+#[derive(Default)]
+pub struct Puzzle;
+
+type Coord = (usize, usize);
+
+pub(super) const REGISTRATION: Registration = Registration::new::<Puzzle>(PuzzleInfo {
+    year: 2023,
+    day: 23,
+    title: "A Long Walk",
+    has_visualization: false,
+    parameters: &[],
+    assumptions: &[],
+    exports: &[
+        Export {
+            name: "dot_a",
+            description: "Junction graph respecting the slopes as Graphviz DOT, with the \
+                          longest path highlighted",
+        },
+        Export {
+            name: "dot_b",
+            description: "Junction graph ignoring the slopes as Graphviz DOT, with the longest \
+                          path highlighted",
+        },
+    ],
+});
+
+impl Solution for Puzzle {
+    fn solve_a(&mut self, input: String) -> Result<Answer, String> {
+        let path_finder = PathFinder::new(parse_input(&input)?);
+        Answer::from(path_finder.find_longest_path()?.length).into()
+    }
+
+    fn solve_b(&mut self, input: String) -> Result<Answer, String> {
+        let input = input.replace(['>', 'v'], ".");
+        let path_finder = PathFinder::new(parse_input(&input)?);
+        Answer::from(path_finder.find_longest_path()?.length).into()
+    }
+
+    fn export(&mut self, input: String, name: &str) -> Result<String, String> {
+        let (input, directed) = match name {
+            "dot_a" => (input, true),
+            "dot_b" => (input.replace(['>', 'v'], "."), false),
+            _ => return Err(format!("Unknown export: {}", name)),
+        };
+        let path_finder = PathFinder::new(parse_input(&input)?);
+        let path = path_finder.find_longest_path()?;
+        Ok(write_dot(&path_finder.junction_graph()?, &path, directed))
+    }
+
+    #[cfg(feature = "ui")]
+    fn get_shapes(
+        &mut self,
+        _input: String,
+        _request: ui_support::DisplayRequest,
+    ) -> Option<ui_support::DisplayResult> {
+        None
+    }
+}
+
+/// Number of partial paths to expand before handing them to the rayon workers
+const BRANCHES: usize = 256;
+
+struct PathFinder {
+    grid: Grid<Cell>,
+}
+impl PathFinder {
+    fn new(grid: Grid<Cell>) -> Self {
+        Self { grid }
+    }
+
+    pub fn find_longest_path(&self) -> Result<LongestPath, String> {
+        let junctions = self.junctions()?;
+        let best = AtomicUsize::new(0);
+        junctions
+            .branches(BRANCHES)
+            .into_par_iter()
+            .filter_map(|mut branch| {
+                let mut found = None;
+                junctions.search(
+                    branch.node,
+                    branch.visited,
+                    branch.length,
+                    &mut branch.path,
+                    &best,
+                    &mut found,
+                );
+                found
+            })
+            .reduce_with(|a, b| if longer(&b, &a) { b } else { a })
+            .map(|(length, path)| LongestPath {
+                length,
+                junctions: path.iter().map(|&i| junctions.coords[i]).collect(),
+            })
+            .ok_or_else(|| "There is no path from the start to the end".to_string())
+    }
+
+    /// Cells where the walk begins and ends, in the top and bottom row
+    fn exits(&self) -> Result<(Coord, Coord), String> {
+        let (width, height) = (self.grid.width(), self.grid.height());
+        if width < 3 || height < 2 {
+            return Err(format!("Maze of {}x{} is too small", width, height));
+        }
+        Ok(((1, 0), (width - 2, height - 1)))
+    }
+
+    /// Cells the walk may step to from `coord`, going downhill only when standing on a slope
+    fn steps(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        let deltas: &[(isize, isize)] = match self.grid[coord] {
+            Cell::Space => &[(-1, 0), (0, -1), (1, 0), (0, 1)],
+            Cell::SlopeRight => &[(1, 0)],
+            Cell::SlopeDown => &[(0, 1)],
+            Cell::Wall => &[],
+        };
+        deltas
+            .iter()
+            .filter_map(move |d| self.grid.offset(coord, *d))
+            .filter(|n| self.grid[*n] != Cell::Wall)
+    }
+
+    /// Junctions, the exits and every cell where three or more paths meet, with the length of
+    /// the longest corridor to each junction that can be reached without passing another one
+    fn junction_graph(&self) -> Result<BTreeMap<Coord, BTreeMap<Coord, usize>>, String> {
+        let (start, end) = self.exits()?;
+        let is_junction = |coord: Coord| {
+            coord == start
+                || coord == end
+                || [(-1, 0), (0, -1), (1, 0), (0, 1)]
+                    .iter()
+                    .filter_map(|d| self.grid.offset(coord, *d))
+                    .filter(|n| self.grid[*n] != Cell::Wall)
+                    .count()
+                    >= 3
+        };
+
+        let mut graph: BTreeMap<Coord, BTreeMap<Coord, usize>> = Default::default();
+        for (coord, cell) in self.grid.iter() {
+            if *cell == Cell::Wall || !is_junction(coord) {
+                continue;
+            }
+            let edges = graph.entry(coord).or_default();
+            for first in self.steps(coord) {
+                let (mut previous, mut current, mut length) = (coord, first, 1);
+                while !is_junction(current) {
+                    // corridors have two open cells next to them, so there is one way onwards
+                    let Some(next) = self.steps(current).find(|n| *n != previous) else {
+                        break;
+                    };
+                    (previous, current, length) = (current, next, length + 1);
+                }
+                if is_junction(current) && current != coord {
+                    let longest = edges.entry(current).or_default();
+                    *longest = length.max(*longest);
+                }
+            }
+        }
+        Ok(graph)
+    }
+
+    /// Junction graph by index, so visited junctions fit in a bitmask
+    fn junctions(&self) -> Result<Junctions, String> {
+        let graph = self.junction_graph()?;
+        let (start, end) = self.exits()?;
+        if graph.len() > u64::BITS as usize {
+            return Err(format!(
+                "The maze has {} junctions, at most {} are supported",
+                graph.len(),
+                u64::BITS
+            ));
+        }
+        let coords: Vec<Coord> = graph.keys().copied().collect();
+        let index: BTreeMap<Coord, usize> =
+            coords.iter().enumerate().map(|(i, c)| (*c, i)).collect();
+        let index_of = |c: &Coord| {
+            index
+                .get(c)
+                .copied()
+                .ok_or_else(|| format!("{:?} is not a junction", c))
+        };
+        let edges = graph
+            .values()
+            .map(|neighbors| {
+                neighbors
+                    .iter()
+                    .map(|(n, d)| Ok((index_of(n)?, *d)))
+                    .collect::<Result<Vec<_>, String>>()
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        let mut best_in = vec![0; coords.len()];
+        for &(to, length) in edges.iter().flatten() {
+            best_in[to] = best_in[to].max(length);
+        }
+        Ok(Junctions {
+            start: index_of(&start)?,
+            end: index_of(&end)?,
+            neighbors: edges
+                .iter()
+                .map(|e| e.iter().fold(0, |mask, (n, _)| mask | 1 << n))
+                .collect(),
+            coords,
+            edges,
+            best_in,
+        })
+    }
+
+    /// Original solution, which compresses the cell graph and searches it single threaded; kept
+    /// for comparison in tests
+    #[cfg(test)]
+    fn reference_longest_path(&self) -> usize {
+        let dim = (self.grid.width(), self.grid.height());
+        let mut graph: BTreeMap<Coord, BTreeMap<Coord, usize>> = Default::default();
+
+        // find all paths
+        self.grid.iter().for_each(|(coord, cell)| {
+            let deltas = match cell {
+                Cell::Space => vec![(-1, 0), (0, -1), (1, 0), (0, 1)],
+                Cell::SlopeRight => vec![(1, 0)],
+                Cell::SlopeDown => vec![(0, 1)],
+                _ => return,
+            };
+            deltas
+                .iter()
+                .filter_map(|d| self.grid.offset(coord, *d))
+                .filter(|n| self.grid[*n] != Cell::Wall)
+                .for_each(|n| {
+                    graph.entry(coord).or_default().insert(n, 1);
+                });
+        });
+
+        // compress graph paths
+        while let Some((&(x, y), _)) = graph.iter().find(|(_, n)| n.len() == 2) {
+            // remove entry for current node which has 2 neighbors
+            let neighbors = graph.remove(&(x, y)).unwrap();
+            let mut neigbors_iter = neighbors.iter();
+            // get two entries
+            let first_n = neigbors_iter.next().unwrap();
+            let second_n = neigbors_iter.next().unwrap();
+            // replace first entry
+            let current_neighbors = graph.get_mut(first_n.0).unwrap();
+            current_neighbors.remove(&(x, y));
+            current_neighbors.insert(*second_n.0, first_n.1 + second_n.1);
+            // replace second entry
+            let current_neighbors = graph.get_mut(second_n.0).unwrap();
+            current_neighbors.remove(&(x, y));
+            current_neighbors.insert(*first_n.0, first_n.1 + second_n.1);
+        }
+        let index_of = |c: &Coord| -> usize { graph.keys().position(|ip| ip == c).unwrap() };
+        let compressed_graph: Vec<Vec<(usize, usize)>> = graph
+            .values()
+            .map(|neighbors| neighbors.iter().map(|(n, d)| (index_of(n), *d)).collect())
+            .collect();
+        dfs(
+            (index_of(&(1, 0)), 0),
+            index_of(&(dim.0 - 2, dim.1 - 1)),
+            &compressed_graph,
+            &mut vec![false; compressed_graph.len()],
+        )
+    }
+}
+
+/// Longest route from the start to the end, through the junctions in the order they are visited
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LongestPath {
+    length: usize,
+    junctions: Vec<Coord>,
+}
+
+impl Display for LongestPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} steps through", self.length)?;
+        for (x, y) in &self.junctions {
+            write!(f, " ({},{})", x, y)?;
+        }
+        Ok(())
+    }
+}
+
+/// Partial path from the start, which one rayon worker completes
+struct Branch {
+    node: usize,
+    visited: u64,
+    length: usize,
+    path: Vec<usize>,
+}
+
+struct Junctions {
+    coords: Vec<Coord>,
+    /// Target and length of the path to every neighboring junction
+    edges: Vec<Vec<(usize, usize)>>,
+    /// Neighboring junctions as a bitmask
+    neighbors: Vec<u64>,
+    /// Length of the longest path into each junction
+    best_in: Vec<usize>,
+    start: usize,
+    end: usize,
+}
+
+impl Junctions {
+    /// Expands partial paths breadth first, until there are enough to keep the workers busy
+    fn branches(&self, count: usize) -> Vec<Branch> {
+        let mut branches = vec![Branch {
+            node: self.start,
+            visited: 1 << self.start,
+            length: 0,
+            path: vec![self.start],
+        }];
+        while branches.len() < count && branches.iter().any(|b| b.node != self.end) {
+            branches = branches
+                .into_iter()
+                .flat_map(|branch| {
+                    if branch.node == self.end {
+                        return vec![branch];
+                    }
+                    self.edges[branch.node]
+                        .iter()
+                        .filter(|(next, _)| branch.visited & 1 << next == 0)
+                        .map(|&(next, length)| {
+                            let mut path = branch.path.clone();
+                            path.push(next);
+                            Branch {
+                                node: next,
+                                visited: branch.visited | 1 << next,
+                                length: branch.length + length,
+                                path,
+                            }
+                        })
+                        .collect()
+                })
+                .collect();
+        }
+        branches
+    }
+
+    /// Depth first search for paths longer than `best`, keeping the longest one in `found`
+    fn search(
+        &self,
+        node: usize,
+        visited: u64,
+        length: usize,
+        path: &mut Vec<usize>,
+        best: &AtomicUsize,
+        found: &mut Option<(usize, Vec<usize>)>,
+    ) {
+        if node == self.end {
+            let candidate = (length, path.clone());
+            if found.as_ref().is_none_or(|f| longer(&candidate, f)) {
+                best.fetch_max(length, Ordering::Relaxed);
+                *found = Some(candidate);
+            }
+            return;
+        }
+        match self.upper_bound(node, visited) {
+            Some(bound) if length + bound >= best.load(Ordering::Relaxed) => {}
+            _ => return,
+        }
+        for &(next, edge) in &self.edges[node] {
+            if visited & 1 << next != 0 {
+                continue;
+            }
+            path.push(next);
+            self.search(next, visited | 1 << next, length + edge, path, best, found);
+            path.pop();
+        }
+    }
+
+    /// Sum of the longest edge into every junction still reachable, none if the end is not
+    fn upper_bound(&self, node: usize, visited: u64) -> Option<usize> {
+        let mut reachable = 0_u64;
+        let mut frontier = self.neighbors[node] & !visited;
+        while frontier != 0 {
+            reachable |= frontier;
+            let mut next = 0;
+            let mut bits = frontier;
+            while bits != 0 {
+                next |= self.neighbors[bits.trailing_zeros() as usize];
+                bits &= bits - 1;
+            }
+            frontier = next & !visited & !reachable;
+        }
+        if reachable & 1 << self.end == 0 {
+            return None;
+        }
+        let mut bound = 0;
+        while reachable != 0 {
+            bound += self.best_in[reachable.trailing_zeros() as usize];
+            reachable &= reachable - 1;
+        }
+        Some(bound)
+    }
+}
+
+/// Prefers the longer path, and the lowest junction indices between paths of equal length
+fn longer(a: &(usize, Vec<usize>), b: &(usize, Vec<usize>)) -> bool {
+    a.0 > b.0 || (a.0 == b.0 && a.1 < b.1)
+}
+
+/// Graphviz graph of the junctions, labeled by coordinates and weighted by the length of the paths
+/// between them, with the edges of `path` in red
+fn write_dot(
+    graph: &BTreeMap<Coord, BTreeMap<Coord, usize>>,
+    path: &LongestPath,
+    directed: bool,
+) -> String {
+    let index: BTreeMap<Coord, usize> = graph.keys().enumerate().map(|(i, c)| (*c, i)).collect();
+    let on_path = |from: &Coord, to: &Coord| {
+        path.junctions.windows(2).any(|pair| {
+            (pair[0] == *from && pair[1] == *to)
+                || (!directed && pair[0] == *to && pair[1] == *from)
+        })
+    };
+    let (kind, arrow) = if directed {
+        ("digraph", "->")
+    } else {
+        ("graph", "--")
+    };
+
+    let mut dot = String::new();
+    writeln!(dot, "{} junctions {{", kind).unwrap();
+    writeln!(dot, "  node [shape=ellipse];").unwrap();
+    for (i, (x, y)) in graph.keys().enumerate() {
+        let shape = if path.junctions.first() == Some(&(*x, *y))
+            || path.junctions.last() == Some(&(*x, *y))
+        {
+            ", shape=doublecircle"
+        } else {
+            ""
+        };
+        writeln!(dot, "  n{} [label=\"{},{}\"{}];", i, x, y, shape).unwrap();
+    }
+    for (from, neighbors) in graph {
+        for (to, length) in neighbors {
+            if !directed && to < from {
+                continue;
+            }
+            let style = if on_path(from, to) {
+                ", color=red, penwidth=3"
+            } else {
+                ""
+            };
+            writeln!(
+                dot,
+                "  n{} {} n{} [label=\"{}\", weight={}{}];",
+                index[from], arrow, index[to], length, length, style
+            )
+            .unwrap();
+        }
+    }
+    writeln!(dot, "}}").unwrap();
+    dot
+}
+
+/// Single threaded search of the original solution, for comparison in tests
+#[cfg(test)]
+fn dfs(
+    current: (usize, usize),
+    end: usize,
+    graph: &[Vec<(usize, usize)>],
+    path: &mut Vec<bool>,
+) -> usize {
+    if current.0 == end {
+        return current.1;
+    }
+    let mut max = usize::MIN;
+    for entry in &graph[current.0] {
+        let next = entry.0;
+        if path[next] {
+            continue;
+        }
+        path[next] = true;
+        let result = dfs((next, current.1 + entry.1), end, graph, path);
+        if result > max {
+            max = result;
+        }
+        path[next] = false;
+    }
+    max
+}
+
+fn parse_input(input: &str) -> Result<Grid<Cell>, String> {
+    Grid::parse(input, Cell::from)
+}
+
+#[derive(PartialEq)]
+enum Cell {
+    Wall,
+    Space,
+    SlopeRight,
+    SlopeDown,
+}
+impl From<char> for Cell {
+    fn from(c: char) -> Self {
+        match c {
+            '#' => Cell::Wall,
+            '.' => Cell::Space,
+            '>' => Cell::SlopeRight,
+            'v' => Cell::SlopeDown,
+            _ => panic!("Unknown cell type: {}", c),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::default_constructed_unit_structs)]
+mod tests {
+    use super::Solution;
+    use super::{parse_input, PathFinder, Puzzle};
+    use common::Answer;
+
+    const TEST_INPUT: &str = "#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#";
+
+    /// Full size maze of 6x6 junctions on a lattice, where every corridor starts and ends with a
+    /// slope and some corridors take a detour; like the puzzle input
+    fn lattice_maze() -> String {
+        let column_gaps = [27, 25, 29, 23, 31];
+        let row_gaps = [26, 30, 24, 28, 22];
+        let xs: Vec<usize> = column_gaps.iter().fold(vec![1], |mut xs, gap| {
+            xs.push(xs.last().unwrap() + gap);
+            xs
+        });
+        let ys: Vec<usize> = row_gaps.iter().fold(vec![1], |mut ys, gap| {
+            ys.push(ys.last().unwrap() + gap);
+            ys
+        });
+        let (width, height) = (xs[5] + 2, ys[5] + 2);
+        let mut maze = vec![vec!['#'; width]; height];
+        maze[0][1] = '.';
+        maze[height - 1][width - 2] = '.';
+
+        for (j, &y) in ys.iter().enumerate() {
+            for (i, pair) in xs.windows(2).enumerate() {
+                let (from, to) = (pair[0], pair[1]);
+                let detour = (i * 3 + j * 5) % 7;
+                let (low, high) = (from + 6, to - 6);
+                let side = if j == 0 { y + detour } else { y - detour };
+                for (x, row) in maze[y].iter_mut().enumerate().take(to + 1).skip(from) {
+                    if x <= low || x >= high {
+                        *row = '.';
+                    }
+                }
+                for row in maze.iter_mut().take(y.max(side) + 1).skip(y.min(side)) {
+                    row[low] = '.';
+                    row[high] = '.';
+                }
+                maze[side][low..=high].fill('.');
+                maze[y][from + 1] = '>';
+                maze[y][to - 1] = '>';
+            }
+        }
+        for (i, &x) in xs.iter().enumerate() {
+            for (j, pair) in ys.windows(2).enumerate() {
+                let (from, to) = (pair[0], pair[1]);
+                let detour = (i * 5 + j * 3) % 6;
+                let (low, high) = (from + 6, to - 6);
+                let side = if i == 0 { x + detour } else { x - detour };
+                for (y, row) in maze.iter_mut().enumerate().take(to + 1).skip(from) {
+                    if y <= low || y >= high {
+                        row[x] = '.';
+                    }
+                    if (low..=high).contains(&y) {
+                        row[side] = '.';
+                    }
+                }
+                maze[low][x.min(side)..=x.max(side)].fill('.');
+                maze[high][x.min(side)..=x.max(side)].fill('.');
+                maze[from + 1][x] = 'v';
+                maze[to - 1][x] = 'v';
+            }
+        }
+        maze.iter()
+            .map(|row| row.iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[tokio::test]
+    async fn part_a() {
+        let mut puzzle = Puzzle::default();
+        assert_eq!(
+            puzzle.solve_a(String::from(TEST_INPUT)),
+            Ok(Answer::from(94))
+        );
+
+        let path_finder = PathFinder::new(parse_input(TEST_INPUT).unwrap());
+        assert_eq!(path_finder.reference_longest_path(), 94);
+        assert_eq!(
+            path_finder.find_longest_path().unwrap().to_string(),
+            "94 steps through (1,0) (3,5) (11,3) (13,13) (21,11) (19,19) (21,22)"
+        );
+    }
+
+    #[tokio::test]
+    async fn part_b() {
+        let mut puzzle = Puzzle::default();
+        assert_eq!(
+            puzzle.solve_b(String::from(TEST_INPUT)),
+            Ok(Answer::from(154))
+        );
+
+        let input = TEST_INPUT.replace(['>', 'v'], ".");
+        let path_finder = PathFinder::new(parse_input(&input).unwrap());
+        assert_eq!(path_finder.reference_longest_path(), 154);
+        let path = path_finder.find_longest_path().unwrap();
+        let graph = path_finder.junction_graph().unwrap();
+        let steps: usize = path
+            .junctions
+            .windows(2)
+            .map(|pair| graph[&pair[0]][&pair[1]])
+            .sum();
+        assert_eq!(steps, 154);
+    }
+
+    #[tokio::test]
+    async fn full_size_maze() {
+        let input = lattice_maze();
+        let path_finder = PathFinder::new(parse_input(&input).unwrap());
+        // with every slope as a node, the graph would not fit in the bitmask of visited junctions
+        assert!(path_finder.junction_graph().unwrap().len() <= 36);
+        let expected = path_finder.reference_longest_path();
+        let mut puzzle = Puzzle::default();
+        assert_eq!(puzzle.solve_a(input.clone()), Ok(Answer::from(expected)));
+
+        let input = input.replace(['>', 'v'], ".");
+        let path_finder = PathFinder::new(parse_input(&input).unwrap());
+        let expected = path_finder.reference_longest_path();
+        assert_eq!(puzzle.solve_b(input), Ok(Answer::from(expected)));
+    }
+
+    #[tokio::test]
+    async fn dot_export() {
+        let mut puzzle = Puzzle::default();
+        for (name, kind, arrow, nodes, edges, length) in [
+            ("dot_a", "digraph", " -> ", 9, 12, 94),
+            ("dot_b", "graph", " -- ", 9, 12, 154),
+        ] {
+            let dot = puzzle.export(String::from(TEST_INPUT), name).unwrap();
+            assert!(dot.starts_with(&format!("{} junctions {{\n", kind)));
+            assert!(dot.ends_with("}\n"));
+            let node_lines: Vec<&str> = dot
+                .lines()
+                .filter(|l| l.contains("[label=\"") && !l.contains(arrow))
+                .collect();
+            assert_eq!(node_lines.len(), nodes);
+            assert!(node_lines.contains(&"  n0 [label=\"1,0\", shape=doublecircle];"));
+            assert!(node_lines.contains(&"  n8 [label=\"21,22\", shape=doublecircle];"));
+
+            let edge_lines: Vec<&str> = dot.lines().filter(|l| l.contains(arrow)).collect();
+            assert_eq!(edge_lines.len(), edges);
+            let weight = |line: &str| -> usize {
+                let start = line.find("weight=").unwrap() + 7;
+                let digits = line[start..]
+                    .split(|c: char| !c.is_ascii_digit())
+                    .next()
+                    .unwrap();
+                digits.parse().unwrap()
+            };
+            assert!(edge_lines
+                .iter()
+                .all(|l| l.contains(&format!("[label=\"{}\"", weight(l)))));
+            assert!(edge_lines
+                .iter()
+                .any(|l| l.starts_with("  n0") && weight(l) == 15));
+            let highlighted: usize = edge_lines
+                .iter()
+                .filter(|l| l.contains("color=red"))
+                .map(|l| weight(l))
+                .sum();
+            assert_eq!(highlighted, length);
+        }
+        assert!(puzzle.export(String::from(TEST_INPUT), "png").is_err());
+    }
+}
@@ -0,0 +1,378 @@
+use std::fmt::{Display, Write};
+
+use super::{Export, Parameter, ParameterField, PuzzleInfo, Registration, Solution};
+use common::{
+    geom::Point3,
+    interval::InclusiveInterval,
+    math::Rational,
+    parse::{self, Line, ParseError},
+    Answer,
+};
+
+pub struct Puzzle {
+    test_area: InclusiveInterval<i64>,
+}
+
+impl Default for Puzzle {
+    fn default() -> Self {
+        Self {
+            test_area: InclusiveInterval::new(200000000000000, 400000000000000),
+        }
+    }
+}
+
+type Stone = (Point3<i128>, Point3<i128>);
+
+pub(super) const REGISTRATION: Registration = Registration::new::<Puzzle>(PuzzleInfo {
+    year: 2023,
+    day: 24,
+    title: "Never Tell Me The Odds",
+    has_visualization: true,
+    parameters: &[
+        Parameter {
+            name: "test_area_min",
+            description: "Lowest X and Y at which intersections are counted for part A",
+        },
+        Parameter {
+            name: "test_area_max",
+            description: "Highest X and Y at which intersections are counted for part A",
+        },
+    ],
+    assumptions: &[],
+    exports: &[Export {
+        name: "crossings",
+        description: "How the paths of every pair of hailstones cross in the test area of part A, \
+                      one pair per line",
+    }],
+});
+
+impl Solution for Puzzle {
+    fn solve_a(&mut self, input: String) -> Result<Answer, String> {
+        let hail = parse_input(&input)?;
+        let count = crossings(&hail, &self.test_area)
+            .filter(|(_, _, crossing)| matches!(crossing, Crossing::Inside(..)))
+            .count();
+        Answer::from(count).into()
+    }
+
+    fn solve_b(&mut self, input: String) -> Result<Answer, String> {
+        let hail = parse_input(&input)?;
+        let (position, _) = find_rock(&hail)?;
+        Answer::from(position.x + position.y + position.z).into()
+    }
+
+    fn parameters(&mut self) -> Vec<ParameterField<'_>> {
+        vec![
+            ParameterField::new_signed("test_area_min", &mut self.test_area.start),
+            ParameterField::new_signed("test_area_max", &mut self.test_area.end),
+        ]
+    }
+
+    fn export(&mut self, input: String, name: &str) -> Result<String, String> {
+        match name {
+            "crossings" => {
+                let hail = parse_input(&input)?;
+                let mut report = String::new();
+                for (a, b, crossing) in crossings(&hail, &self.test_area) {
+                    writeln!(report, "{}", describe_crossing(a, b, &crossing)).unwrap();
+                }
+                Ok(report)
+            }
+            _ => Err(format!("Unknown export: {}", name)),
+        }
+    }
+
+    #[cfg(feature = "ui")]
+    fn get_shapes(
+        &mut self,
+        input: String,
+        _request: ui_support::DisplayRequest,
+    ) -> Option<ui_support::DisplayResult> {
+        build_shapes_for_ui(input, &self.test_area)
+            .map(|s| s.into())
+            .ok()
+    }
+}
+
+/// How the paths of two hailstones cross in the x/y plane
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Crossing {
+    Parallel,
+    Past,
+    Outside(Rational, Rational),
+    Inside(Rational, Rational),
+}
+
+impl Display for Crossing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parallel => write!(f, "paths are parallel"),
+            Self::Past => write!(f, "paths crossed in the past"),
+            Self::Outside(x, y) => write!(
+                f,
+                "paths cross outside the test area at x={:.3}, y={:.3}",
+                x.to_f64(),
+                y.to_f64()
+            ),
+            Self::Inside(x, y) => write!(
+                f,
+                "paths cross inside the test area at x={:.3}, y={:.3}",
+                x.to_f64(),
+                y.to_f64()
+            ),
+        }
+    }
+}
+
+/// One line about a pair of hailstones, numbered from 1 like in the input
+fn describe_crossing(a: usize, b: usize, crossing: &Crossing) -> String {
+    format!("Hailstones {} and {}: {}", a + 1, b + 1, crossing)
+}
+
+/// Every pair of hailstones, by index, with how their paths cross
+fn crossings<'a>(
+    hail: &'a [Stone],
+    area: &'a InclusiveInterval<i64>,
+) -> impl Iterator<Item = (usize, usize, Crossing)> + 'a {
+    hail.iter().enumerate().flat_map(move |(index, a)| {
+        hail.iter()
+            .enumerate()
+            .skip(index + 1)
+            .map(move |(other, b)| (index, other, crossing(a, b, area)))
+    })
+}
+
+/// Exact crossing of the paths, ignoring the z axis
+fn crossing(a: &Stone, b: &Stone, area: &InclusiveInterval<i64>) -> Crossing {
+    let (position_a, velocity_a) = (a.0.xy(), a.1.xy());
+    let (position_b, velocity_b) = (b.0.xy(), b.1.xy());
+    let determinant = velocity_a.x * velocity_b.y - velocity_a.y * velocity_b.x;
+    if determinant == 0 {
+        return Crossing::Parallel;
+    }
+    let distance = position_b - position_a;
+    let time_a = Rational::new(
+        distance.x * velocity_b.y - distance.y * velocity_b.x,
+        determinant,
+    );
+    let time_b = Rational::new(
+        distance.x * velocity_a.y - distance.y * velocity_a.x,
+        determinant,
+    );
+    let zero = Rational::from(0);
+    if time_a < zero || time_b < zero {
+        return Crossing::Past;
+    }
+    let x = Rational::from(position_a.x) + time_a * Rational::from(velocity_a.x);
+    let y = Rational::from(position_a.y) + time_a * Rational::from(velocity_a.y);
+    let (min, max) = (
+        Rational::from(area.start as i128),
+        Rational::from(area.end as i128),
+    );
+    if (min..=max).contains(&x) && (min..=max).contains(&y) {
+        Crossing::Inside(x, y)
+    } else {
+        Crossing::Outside(x, y)
+    }
+}
+
+/// Position and velocity of the rock that hits every hailstone; the first hailstone triple that
+/// pins the rock down determines it, the other hailstones are checked against it
+fn find_rock(hail: &[Stone]) -> Result<Stone, String> {
+    for (index, reference) in hail.iter().enumerate() {
+        for (offset, a) in hail.iter().enumerate().skip(index + 1) {
+            for b in hail.iter().skip(offset + 1) {
+                if let Some(rock) = rock_through(reference, a, b)? {
+                    return match hail.iter().position(|stone| !hits(&rock, stone)) {
+                        Some(missed) => Err(format!(
+                            "No single rock hits all hailstones, hailstone {} is missed",
+                            missed + 1
+                        )),
+                        None => Ok(rock),
+                    };
+                }
+            }
+        }
+    }
+    Err(String::from(
+        "Hailstones are not independent enough to determine the rock",
+    ))
+}
+
+/// Solves for the rock hitting the three hailstones, or `None` if they do not determine it.
+///
+/// Seen from `reference`, the rock passes through the origin, so it lies in the plane through
+/// the origin and `a`'s path as well as in the one through `b`'s path. Where `a` crosses the
+/// plane of `b` (and the other way around) gives both collision times, and from those the rock.
+/// Working relative to `reference` keeps all products within `i128`.
+fn rock_through(reference: &Stone, a: &Stone, b: &Stone) -> Result<Option<Stone>, String> {
+    let (position_a, velocity_a) = (a.0 - reference.0, a.1 - reference.1);
+    let (position_b, velocity_b) = (b.0 - reference.0, b.1 - reference.1);
+    let normal_a = position_a.cross(velocity_a);
+    let normal_b = position_b.cross(velocity_b);
+    let denominator_a = velocity_a.dot(normal_b);
+    let denominator_b = velocity_b.dot(normal_a);
+    if normal_a.cross(normal_b) == Point3::default() || denominator_a == 0 || denominator_b == 0 {
+        return Ok(None);
+    }
+    let time_a = Rational::new(-position_a.dot(normal_b), denominator_a);
+    let time_b = Rational::new(-position_b.dot(normal_a), denominator_b);
+    if time_a == time_b {
+        return Ok(None);
+    }
+    let (Some(time_a), Some(time_b)) = (time_a.to_integer(), time_b.to_integer()) else {
+        return Err(String::from(
+            "No single rock hits all hailstones at whole times",
+        ));
+    };
+
+    let hit_a = a.0 + a.1 * time_a;
+    let hit_b = b.0 + b.1 * time_b;
+    let distance = hit_b - hit_a;
+    let elapsed = time_b - time_a;
+    if [distance.x, distance.y, distance.z]
+        .iter()
+        .any(|d| d % elapsed != 0)
+    {
+        return Err(String::from(
+            "No single rock with a whole velocity hits all hailstones",
+        ));
+    }
+    let velocity = Point3::new(
+        distance.x / elapsed,
+        distance.y / elapsed,
+        distance.z / elapsed,
+    );
+    Ok(Some((hit_a - velocity * time_a, velocity)))
+}
+
+/// Whether the rock and the hailstone are at the same place at some time from 0 on
+fn hits(rock: &Stone, stone: &Stone) -> bool {
+    let distance = stone.0 - rock.0;
+    let closing_speed = rock.1 - stone.1;
+    if closing_speed == Point3::default() {
+        return distance == Point3::default();
+    }
+    distance.cross(closing_speed) == Point3::default() && distance.dot(closing_speed) >= 0
+}
+
+fn parse_input(input: &str) -> Result<Vec<Stone>, ParseError> {
+    parse::lines(input)
+        .map(|line| {
+            let (position, velocity) = line.split_once("@")?;
+            Ok((parse_vector(position)?, parse_vector(velocity)?))
+        })
+        .collect()
+}
+
+fn parse_vector(line: Line<'_>) -> Result<Point3<i128>, ParseError> {
+    match line.list(",")?[..] {
+        [x, y, z] => Ok(Point3::new(x, y, z)),
+        _ => Err(line.error("Expected 3 values")),
+    }
+}
+
+#[cfg(feature = "ui")]
+fn build_shapes_for_ui(
+    input: String,
+    area: &InclusiveInterval<i64>,
+) -> Result<Vec<ui_support::DisplayData>, String> {
+    let hail = parse_input(&input)?;
+    Ok(crossings(&hail, area)
+        .map(|(a, b, crossing)| {
+            ui_support::DisplayData::log_line(describe_crossing(a, b, &crossing))
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use super::{crossing, parse_input, Crossing, Puzzle};
+    use common::{interval::InclusiveInterval, math::Rational, Answer};
+
+    const TEST_INPUT: &str = "19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3";
+
+    #[tokio::test]
+    async fn part_a() {
+        let mut puzzle = Puzzle {
+            test_area: InclusiveInterval::new(7, 27),
+        };
+        assert_eq!(
+            puzzle.solve_a(String::from(TEST_INPUT)),
+            Ok(Answer::from(2))
+        )
+    }
+
+    #[tokio::test]
+    async fn crossings() {
+        let hail = parse_input(TEST_INPUT).unwrap();
+        let area = InclusiveInterval::new(7, 27);
+        let third = |n| Rational::new(n, 3);
+        let fifth = |n| Rational::new(n, 5);
+        let whole = Rational::from;
+        for (a, b, expected) in [
+            (0, 1, Crossing::Inside(third(43), third(46))),
+            (0, 2, Crossing::Inside(third(35), third(50))),
+            (0, 3, Crossing::Outside(fifth(31), fifth(97))),
+            (0, 4, Crossing::Past),
+            (1, 2, Crossing::Parallel),
+            (1, 3, Crossing::Outside(whole(-6), whole(-5))),
+            (1, 4, Crossing::Past),
+            (2, 3, Crossing::Outside(whole(-2), whole(3))),
+            (2, 4, Crossing::Past),
+            (3, 4, Crossing::Past),
+        ] {
+            assert_eq!(crossing(&hail[a], &hail[b], &area), expected, "{} {}", a, b);
+        }
+
+        let mut puzzle = Puzzle { test_area: area };
+        let report = puzzle
+            .export(String::from(TEST_INPUT), "crossings")
+            .unwrap();
+        assert_eq!(report.lines().count(), 10);
+        assert_eq!(
+            report.lines().next(),
+            Some("Hailstones 1 and 2: paths cross inside the test area at x=14.333, y=15.333")
+        );
+        assert!(report.contains("Hailstones 2 and 3: paths are parallel\n"));
+        assert!(report.contains("Hailstones 4 and 5: paths crossed in the past\n"));
+        assert!(puzzle.export(String::from(TEST_INPUT), "csv").is_err());
+    }
+
+    #[tokio::test]
+    async fn part_b() {
+        let mut puzzle = Puzzle::default();
+        assert_eq!(
+            puzzle.solve_b(String::from(TEST_INPUT)),
+            Ok(Answer::from(47))
+        );
+
+        // the rock through the first three hailstones misses the extra one
+        let missed = format!("{}\n0, 0, 0 @ 1, 1, 1", TEST_INPUT);
+        assert_eq!(
+            puzzle.solve_b(missed),
+            Err(String::from(
+                "No single rock hits all hailstones, hailstone 6 is missed"
+            ))
+        );
+        let fractional = TEST_INPUT.replace("-2, -2, -4", "-2, -3, -4");
+        assert_eq!(
+            puzzle.solve_b(fractional),
+            Err(String::from(
+                "No single rock hits all hailstones at whole times"
+            ))
+        );
+        let parallel = "0, 0, 0 @ 1, 0, 0\n0, 1, 0 @ 1, 0, 0\n0, 2, 0 @ 1, 0, 0";
+        assert_eq!(
+            puzzle.solve_b(String::from(parallel)),
+            Err(String::from(
+                "Hailstones are not independent enough to determine the rock"
+            ))
+        );
+    }
+}
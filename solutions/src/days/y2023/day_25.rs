@@ -0,0 +1,321 @@
+use std::collections::{BTreeSet, VecDeque};
+
+use super::{Parameter, ParameterField, PuzzleInfo, Registration, Solution};
+use common::{
+    parse::{self, ParseError},
+    search, Answer,
+};
+
+pub struct Puzzle {
+    cut_size: usize,
+}
+
+impl Default for Puzzle {
+    fn default() -> Self {
+        Self { cut_size: 3 }
+    }
+}
+
+pub(super) const REGISTRATION: Registration = Registration::new::<Puzzle>(PuzzleInfo {
+    year: 2023,
+    day: 25,
+    title: "Snowverload",
+    has_visualization: true,
+    parameters: &[Parameter {
+        name: "cut_size",
+        description: "Number of wires to cut to split the graph into two groups",
+    }],
+    assumptions: &[
+        "Cutting exactly `cut_size` wires splits the graph into two groups",
+        "Both groups have at least two components",
+        "Some component has more than `cut_size` wires",
+    ],
+    exports: &[],
+});
+
+impl Solution for Puzzle {
+    fn solve_a(&mut self, input: String) -> Result<Answer, String> {
+        let graph = Graph::parse(&input)?;
+        let cut = graph.find_cut(self.cut_size)?;
+        Answer::from(cut.group_size * (graph.names.len() - cut.group_size)).into()
+    }
+
+    fn solve_b(&mut self, _input: String) -> Result<Answer, String> {
+        Answer::from("Merry Christmas").into()
+    }
+
+    fn parameters(&mut self) -> Vec<ParameterField<'_>> {
+        vec![ParameterField::new_unsigned("cut_size", &mut self.cut_size)]
+    }
+
+    #[cfg(feature = "ui")]
+    fn get_shapes(
+        &mut self,
+        input: String,
+        _request: ui_support::DisplayRequest,
+    ) -> Option<ui_support::DisplayResult> {
+        build_shapes_for_ui(input, self.cut_size)
+            .map(|s| s.into())
+            .ok()
+    }
+}
+
+/// Wires to cut, and the size of the group on the side of the source component
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Cut<'a> {
+    wires: Vec<(&'a str, &'a str)>,
+    group_size: usize,
+}
+
+/// Undirected graph of components, indexed in name order so results do not depend on hashing
+struct Graph<'a> {
+    names: Vec<&'a str>,
+    wires: Vec<(usize, usize)>,
+    /// Per component its neighbors, with the index of the wire leading there
+    adjacency: Vec<Vec<(usize, usize)>>,
+}
+
+impl<'a> Graph<'a> {
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        let mut pairs = BTreeSet::new();
+        for line in parse::lines(input) {
+            let (key, values) = line.key_value()?;
+            for value in values.split(" ") {
+                pairs.insert((key.min(value.text), key.max(value.text)));
+            }
+        }
+        let names = pairs
+            .iter()
+            .flat_map(|(a, b)| [*a, *b])
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        let index = |name: &str| names.binary_search(&name).unwrap();
+        let wires = pairs
+            .iter()
+            .map(|(a, b)| (index(a), index(b)))
+            .collect::<Vec<_>>();
+        let mut adjacency = vec![vec![]; names.len()];
+        for (wire, &(a, b)) in wires.iter().enumerate() {
+            adjacency[a].push((b, wire));
+            adjacency[b].push((a, wire));
+        }
+        Ok(Self {
+            names,
+            wires,
+            adjacency,
+        })
+    }
+
+    /// Finds `cut_size` wires which split the graph in two groups of at least two components.
+    /// The source is a component with more than `cut_size` wires, so it is never cut off on its
+    /// own. It is on one side of any cut, so the max flow from it to some component on the other
+    /// side is exactly `cut_size`, and the components still reachable after that flow form its
+    /// group. Sinks that are only cut off on their own are skipped.
+    fn find_cut(&self, cut_size: usize) -> Result<Cut<'a>, String> {
+        let source = (0..self.names.len())
+            .find(|&node| self.adjacency[node].len() > cut_size)
+            .ok_or(format!("No component has more than {} wires", cut_size))?;
+        for sink in (0..self.names.len()).filter(|&sink| sink != source) {
+            let mut flow = vec![0_i8; self.wires.len()];
+            let mut paths = 0;
+            while paths <= cut_size && self.augment(&mut flow, source, sink) {
+                paths += 1;
+            }
+            if paths != cut_size {
+                continue;
+            }
+            let group = self.reachable(&flow, source);
+            let group_size = group.iter().filter(|in_group| **in_group).count();
+            if self.names.len() - group_size < 2 {
+                continue;
+            }
+            let wires = self
+                .wires
+                .iter()
+                .filter(|(a, b)| group[*a] != group[*b])
+                .map(|&(a, b)| (self.names[a], self.names[b]))
+                .collect();
+            return Ok(Cut { wires, group_size });
+        }
+        Err(format!(
+            "No cut of {} wires splits the graph into two groups",
+            cut_size
+        ))
+    }
+
+    /// Flow on `wire` from `from` to the other end can still grow; a wire carries at most one
+    /// unit of flow, in either direction, where positive flow runs from its first component
+    fn residual(&self, flow: &[i8], wire: usize, from: usize) -> bool {
+        if self.wires[wire].0 == from {
+            flow[wire] < 1
+        } else {
+            flow[wire] > -1
+        }
+    }
+
+    /// Neighbors of `node` over wires with residual capacity, with the wire leading there
+    fn residual_neighbors<'f>(
+        &'f self,
+        flow: &'f [i8],
+        node: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + 'f {
+        self.adjacency[node]
+            .iter()
+            .copied()
+            .filter(move |&(_, wire)| self.residual(flow, wire, node))
+    }
+
+    /// Components reachable from `source` over wires with residual capacity
+    fn reachable(&self, flow: &[i8], source: usize) -> Vec<bool> {
+        let mut reached = vec![false; self.names.len()];
+        reached[source] = true;
+        let mut queue = VecDeque::from([source]);
+        while let Some(node) = queue.pop_front() {
+            for (next, _) in self.residual_neighbors(flow, node) {
+                if !reached[next] {
+                    reached[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+        reached
+    }
+
+    /// Pushes one unit of flow along a shortest residual path, if there is one
+    fn augment(&self, flow: &mut [i8], source: usize, sink: usize) -> bool {
+        let route = search::bfs(
+            source,
+            |&node| self.residual_neighbors(flow, node).map(|(next, _)| next),
+            |&node| node == sink,
+        );
+        let Some(route) = route else {
+            return false;
+        };
+        for step in route.path.windows(2) {
+            let (_, wire) = self
+                .residual_neighbors(flow, step[0])
+                .find(|&(next, _)| next == step[1])
+                .expect("Path follows residual wires");
+            flow[wire] += if self.wires[wire].0 == step[0] { 1 } else { -1 };
+        }
+        true
+    }
+}
+
+#[cfg(feature = "ui")]
+fn build_shapes_for_ui(
+    input: String,
+    cut_size: usize,
+) -> Result<Vec<ui_support::DisplayData>, String> {
+    let graph = Graph::parse(&input)?;
+    let cut = graph.find_cut(cut_size)?;
+    Ok(cut
+        .wires
+        .iter()
+        .map(|(a, b)| ui_support::DisplayData::log_line(format!("Cut wire {a}/{b}")))
+        .chain([ui_support::DisplayData::log_line(format!(
+            "Groups of {} and {} components",
+            cut.group_size,
+            graph.names.len() - cut.group_size
+        ))])
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use super::{Graph, Puzzle};
+    use common::Answer;
+
+    const TEST_INPUT: &str = "jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr";
+
+    #[tokio::test]
+    async fn part_a() {
+        let mut puzzle = Puzzle::default();
+        assert_eq!(
+            puzzle.solve_a(String::from(TEST_INPUT)),
+            Ok(Answer::from(54))
+        )
+    }
+
+    #[tokio::test]
+    async fn part_b() {
+        let mut puzzle = Puzzle::default();
+        assert_eq!(
+            puzzle.solve_b(String::from(TEST_INPUT)),
+            Ok(Answer::from("Merry Christmas"))
+        )
+    }
+
+    #[test]
+    fn cut_wires() {
+        let graph = Graph::parse(TEST_INPUT).unwrap();
+        let cut = graph.find_cut(3).unwrap();
+        assert_eq!(
+            cut.wires,
+            vec![("bvb", "cmg"), ("hfx", "pzl"), ("jqt", "nvd")]
+        );
+        assert_eq!(cut.group_size * (graph.names.len() - cut.group_size), 54);
+    }
+
+    /// Two groups of four, all wired to each other, joined by two wires
+    const TWO_WIRES_INPUT: &str = "a: b c d
+b: c d
+c: d
+e: f g h
+f: g h
+g: h
+a: e
+d: h";
+
+    #[tokio::test]
+    async fn other_cut_size() {
+        let mut puzzle = Puzzle { cut_size: 2 };
+        assert_eq!(
+            puzzle.solve_a(String::from(TWO_WIRES_INPUT)),
+            Ok(Answer::from(16))
+        );
+        let graph = Graph::parse(TWO_WIRES_INPUT).unwrap();
+        assert_eq!(
+            graph.find_cut(2).map(|cut| cut.wires),
+            Ok(vec![("a", "e"), ("d", "h")])
+        );
+    }
+
+    #[tokio::test]
+    async fn no_cut() {
+        let mut puzzle = Puzzle { cut_size: 2 };
+        assert_eq!(
+            puzzle.solve_a(String::from(TEST_INPUT)),
+            Err(String::from(
+                "No cut of 2 wires splits the graph into two groups"
+            ))
+        );
+        // a component with only two wires is not a group of its own
+        let mut puzzle = Puzzle { cut_size: 2 };
+        assert_eq!(
+            puzzle.solve_a(String::from("a: b c d\nb: c d\nc: d\ne: a b")),
+            Err(String::from(
+                "No cut of 2 wires splits the graph into two groups"
+            ))
+        );
+        let mut puzzle = Puzzle { cut_size: 4 };
+        assert_eq!(
+            puzzle.solve_a(String::from(TWO_WIRES_INPUT)),
+            Err(String::from("No component has more than 4 wires"))
+        );
+    }
+}
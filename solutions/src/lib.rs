@@ -1,14 +1,50 @@
+use chrono::{Datelike, Local, TimeZone};
 use std::{env, fs, path};
 
 pub mod days;
+pub mod results;
 
-pub async fn get_input(day: u8, aoc_session: Option<String>) -> Result<String, String> {
+/// Year whose inputs were cached directly in the inputs folder, before inputs were split per year
+const LEGACY_INPUT_YEAR: u16 = 2023;
+
+/// Returns the last day of the given year which is unlocked, 0 if the year hasn't started yet
+pub fn get_max_day(year: u16) -> u8 {
+    let now = Local::now();
+    if now.year() == year as i32 && now.month() == 12 {
+        (now.day() as u8).min(25)
+    } else if now.lt(&Local
+        .with_ymd_and_hms(year as i32, 12, 1, 0, 0, 0)
+        .earliest()
+        .unwrap())
+    {
+        0
+    } else {
+        25
+    }
+}
+
+/// Location where the input of a day is cached
+pub fn get_input_path(year: u16, day: u8) -> Result<path::PathBuf, String> {
     let filename = format!("input_{}.txt", day);
+    let inputs_dir = get_inputs_dir()?;
+    let input_path = inputs_dir.join(year.to_string()).join(&filename);
+    let legacy_path = inputs_dir.join(&filename);
+    if year == LEGACY_INPUT_YEAR && !input_path.exists() && legacy_path.is_file() {
+        return Ok(legacy_path);
+    }
+    Ok(input_path)
+}
+
+pub(crate) fn get_inputs_dir() -> Result<path::PathBuf, String> {
     let current_dir = &env::current_dir().map_err(|e| e.to_string())?;
     let cwd = std::env::var("CARGO_MANIFEST_DIR")
         .map(|md| path::Path::new(&md).join("../"))
         .unwrap_or_else(|_| path::Path::new(current_dir).to_path_buf());
-    let input_path = cwd.join("inputs").join(&filename);
+    Ok(cwd.join("inputs"))
+}
+
+pub async fn get_input(year: u16, day: u8, aoc_session: Option<String>) -> Result<String, String> {
+    let input_path = get_input_path(year, day)?;
     let aoc_session = aoc_session.or(env::var_os("AOC_SESSION").and_then(|v| v.into_string().ok()));
 
     if input_path.is_dir() {
@@ -30,7 +66,7 @@ pub async fn get_input(day: u8, aoc_session: Option<String>) -> Result<String, S
     let repo_url = env!("CARGO_PKG_REPOSITORY");
     let authors = env!("CARGO_PKG_AUTHORS");
 
-    let url = format!("https://adventofcode.com/{}/day/{}/input", "2023", day);
+    let url = format!("https://adventofcode.com/{}/day/{}/input", year, day);
     let client = reqwest::Client::new();
     let response = client
         .get(url)
@@ -1,11 +1,13 @@
-use aoc2023::get_input;
-use chrono::{Datelike, Local, TimeZone};
+use aoc2023::results::DayResults;
+use aoc2023::{get_input, get_input_path, get_max_day};
 use clap::ArgAction;
-use clap::Parser;
+use clap::{Parser, Subcommand};
+use common::Answer;
 use std::ops::Add;
+use std::path::PathBuf;
 use std::time::Duration;
 
-mod days;
+use aoc2023::days::{self, Registration};
 
 #[derive(Parser, Debug)]
 #[command(name = "AOC 2023")]
@@ -13,47 +15,100 @@ mod days;
 #[command(version = "1.0")]
 #[command(about, long_about = None)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
     /// AOC Session id; if not set uses ENV var AOC_SESSION
     #[arg(long)]
     aoc_session: Option<String>,
+    /// Puzzle year; defaults to the most recent year for running, and all years for listing
+    #[arg(short, long, global = true)]
+    year: Option<u16>,
     /// Puzzle day to run
     #[arg(short, long)]
     day: Option<u8>,
     // Run all days
     #[arg(short, long, action = ArgAction::SetTrue)]
     all: bool,
+    /// Set a puzzle parameter, can be repeated; see `list --details` for the parameters of each day
+    #[arg(short, long = "param", value_name = "KEY=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+    /// Store the answers of this run as the expected answers; ignored for days with overridden
+    /// parameters
+    #[arg(long, action = ArgAction::SetTrue)]
+    record_answers: bool,
+}
+
+fn parse_param(param: &str) -> Result<(String, String), String> {
+    param
+        .split_once('=')
+        .map(|(key, value)| (key.trim().to_string(), value.to_string()))
+        .ok_or(format!("Expected KEY=VALUE, got: {}", param))
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Show the status of every registered day
+    #[command(long_flag = "list")]
+    List {
+        /// Rerun days with a cached input and recorded answers before listing
+        #[arg(long, action = ArgAction::SetTrue)]
+        verify: bool,
+        /// Also print the parameters and input assumptions of each day
+        #[arg(long, action = ArgAction::SetTrue)]
+        details: bool,
+    },
+    /// Write one of the exports of the selected day; see `list --details` for the exports of each
+    /// day
+    Export {
+        /// Name of the export
+        name: String,
+        /// File to write to; prints to stdout if not set
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
 }
 
 #[tokio::main]
 async fn main() {
     let args = Args::parse();
+    if let Some(Command::List { verify, details }) = args.command {
+        list_days(args.year, verify, details);
+        return;
+    }
     if args.day.is_none() && !args.all {
-        panic!("Either select a day with --day <DAY> or run all with --all");
+        panic!(
+            "Either select a day with --day <DAY>, run all with --all or show all days with list"
+        );
     }
-    let now = Local::now();
-    let max_day = if now.year() == 2023 && now.month() == 12 {
-        now.day() as u8
-    } else if now.lt(&Local
-        .with_ymd_and_hms(2023, 12, 1, 0, 0, 0)
-        .earliest()
-        .unwrap())
-    {
-        0
-    } else {
-        25
-    };
+    let year = args.year.unwrap_or_else(days::latest_year);
+    if !days::years().contains(&year) {
+        panic!("No puzzles registered for {}", year);
+    }
+    let max_day = get_max_day(year);
 
     if let Some(day) = args.day {
         if day > max_day {
-            panic!("Day {} is not yet available", day);
+            panic!("Day {} of {} is not yet available", day, year);
+        }
+        let declared = days::get_registration(year, day).map_or(&[][..], |r| r.info.parameters);
+        if let Some((key, _)) = args
+            .params
+            .iter()
+            .find(|(key, _)| !declared.iter().any(|p| p.name == key))
+        {
+            panic!("Day {} of {} has no parameter {}", day, year, key);
+        }
+        if let Some(Command::Export { name, output }) = &args.command {
+            export_day(year, day, name, output.as_ref(), &args).await;
+        } else {
+            execute_day(year, day, &args).await;
         }
-        execute_day(day, args.aoc_session.clone()).await;
     } else {
         let mut total_duration = Duration::ZERO;
-        for day in 1..=25 {
+        for registration in days::registry_for_year(year) {
+            let day = registration.info.day;
             if day <= max_day {
-                total_duration =
-                    total_duration.add(execute_day(day, args.aoc_session.clone()).await);
+                total_duration = total_duration.add(execute_day(year, day, &args).await);
             } else {
                 println!("Day {} skipped", day);
             }
@@ -62,13 +117,113 @@ async fn main() {
     }
 }
 
-async fn execute_day(day: u8, aoc_session: Option<String>) -> Duration {
-    let mut solution = match days::get_day(day) {
-        Ok(solution) => solution,
-        Err(_) => return Duration::ZERO,
-    };
+fn list_days(year: Option<u16>, verify: bool, details: bool) {
+    let registrations = days::registry()
+        .filter(|r| year.is_none_or(|y| r.info.year == y))
+        .collect::<Vec<_>>();
+    let title_width = registrations
+        .iter()
+        .map(|r| r.info.title.len())
+        .max()
+        .unwrap_or(0);
+    println!(
+        "{:<4}  {:>3}  {:<title_width$}  {:<8}  {:<6}  {:<7}  {:<6}  {:<13}  {:>10}",
+        "Year",
+        "Day",
+        "Title",
+        "Unlocked",
+        "Input",
+        "Answers",
+        "Status",
+        "Visualization",
+        "Last time"
+    );
+    for registration in registrations {
+        let info = &registration.info;
+        let unlocked = info.day <= get_max_day(info.year);
+        let cached = get_input_path(info.year, info.day)
+            .map(|p| p.is_file())
+            .unwrap_or(false);
+        let mut results = DayResults::load(info.year, info.day).unwrap_or_else(|e| {
+            eprintln!(
+                "Day {} of {} has unreadable results: {}",
+                info.day, info.year, e
+            );
+            DayResults::default()
+        });
+        if verify && unlocked && cached && results.has_expected() {
+            if let Ok(input) = std::fs::read_to_string(get_input_path(info.year, info.day).unwrap())
+            {
+                let mut solution = registration.create();
+                let outcome = run_solution(solution.as_mut(), input);
+                outcome.store(&mut results);
+                if let Err(e) = results.save(info.year, info.day) {
+                    eprintln!("Failed to store results: {}", e);
+                }
+            }
+        }
+        let answers = match (&results.expected_a, &results.expected_b) {
+            (Some(_), Some(_)) => "A+B",
+            (Some(_), None) => "A",
+            (None, Some(_)) => "B",
+            (None, None) => "-",
+        };
+        let status = match results.passes() {
+            Some(true) => "pass",
+            Some(false) => "FAIL",
+            None => "-",
+        };
+        let last_time = results
+            .last_time
+            .map(|t| format!("{:.2?}", t))
+            .unwrap_or(String::from("-"));
+        println!(
+            "{:<4}  {:>3}  {:<title_width$}  {:<8}  {:<6}  {:<7}  {:<6}  {:<13}  {:>10}",
+            info.year,
+            info.day,
+            info.title,
+            if unlocked { "yes" } else { "no" },
+            if cached { "cached" } else { "-" },
+            answers,
+            status,
+            if info.has_visualization { "yes" } else { "-" },
+            last_time
+        );
+        if details {
+            for parameter in info.parameters {
+                println!(
+                    "    parameter {}: {}",
+                    parameter.name, parameter.description
+                );
+            }
+            for assumption in info.assumptions {
+                println!("    assumes: {}", assumption);
+            }
+            for export in info.exports {
+                println!("    export {}: {}", export.name, export.description);
+            }
+        }
+    }
+}
+
+struct RunOutcome {
+    result_a: Result<Answer, String>,
+    result_b: Result<Answer, String>,
+    time_a: Duration,
+    time_b: Duration,
+    time: Duration,
+}
+
+impl RunOutcome {
+    fn store(&self, results: &mut DayResults) {
+        results.last_a = self.result_a.as_ref().ok().map(|a| a.get_result());
+        results.last_b = self.result_b.as_ref().ok().map(|a| a.get_result());
+        results.last_time = Some(self.time);
+    }
+}
 
-    let input_a = get_input(day, aoc_session).await.unwrap();
+fn run_solution(solution: &mut (dyn days::Solution + Send), input: String) -> RunOutcome {
+    let input_a = input;
     let input_b = input_a.clone();
 
     let start = std::time::Instant::now();
@@ -78,19 +233,116 @@ async fn execute_day(day: u8, aoc_session: Option<String>) -> Duration {
     let result_b = solution.solve_b(input_b);
     let time_b = start_b.elapsed();
     let time = start.elapsed();
+    RunOutcome {
+        result_a,
+        result_b,
+        time_a,
+        time_b,
+        time,
+    }
+}
 
-    match result_a {
-        Ok(answer) => println!("Day {}, Part A = {}", day, answer.get_result()),
+/// The given parameters that the day declares
+fn declared_params<'a>(
+    registration: &'a Registration,
+    args: &'a Args,
+) -> impl Iterator<Item = &'a (String, String)> {
+    args.params
+        .iter()
+        .filter(|(key, _)| registration.info.parameters.iter().any(|p| p.name == key))
+}
+
+/// Creates the solution of a day, with all given parameters that the day declares
+fn create_solution(registration: &Registration, args: &Args) -> Box<dyn days::Solution + Send> {
+    let mut solution = registration.create();
+    for (key, value) in declared_params(registration, args) {
+        if let Err(e) = days::set_parameter(solution.as_mut(), key, value) {
+            panic!("Day {}: {}", registration.info.day, e);
+        }
+    }
+    solution
+}
+
+/// Writes an export of a day to a file, or to stdout
+async fn export_day(year: u16, day: u8, name: &str, output: Option<&PathBuf>, args: &Args) {
+    let registration = days::get_registration(year, day)
+        .unwrap_or_else(|| panic!("Day {} of {} is not registered", day, year));
+    if !registration.info.exports.iter().any(|e| e.name == name) {
+        panic!("Day {} of {} has no export {}", day, year, name);
+    }
+    let mut solution = create_solution(registration, args);
+    let input = get_input(year, day, args.aoc_session.clone())
+        .await
+        .unwrap();
+    let contents = solution
+        .export(input, name)
+        .unwrap_or_else(|e| panic!("Day {}, export {} failed! {}", day, name, e));
+    match output {
+        Some(path) => {
+            if let Err(e) = std::fs::write(path, contents) {
+                panic!("Writing {} failed! {}", path.display(), e);
+            }
+        }
+        None => print!("{}", contents),
+    }
+}
+
+/// Runs a day, with all given parameters that the day declares. Results of runs with overridden
+/// parameters are not stored, as they do not answer the puzzle itself.
+async fn execute_day(year: u16, day: u8, args: &Args) -> Duration {
+    let registration = match days::get_registration(year, day) {
+        Some(registration) => registration,
+        None => return Duration::ZERO,
+    };
+    let mut solution = create_solution(registration, args);
+
+    let input = get_input(year, day, args.aoc_session.clone())
+        .await
+        .unwrap();
+    let outcome = run_solution(solution.as_mut(), input);
+
+    let mut results = DayResults::load(year, day).unwrap_or_default();
+    if declared_params(registration, args).next().is_some() {
+        println!("Day {}, parameters overridden, results not stored", day);
+    } else {
+        outcome.store(&mut results);
+        if args.record_answers {
+            results.expected_a = results.last_a.clone();
+            results.expected_b = results.last_b.clone();
+        }
+        if let Err(e) = results.save(year, day) {
+            println!("Day {}, storing results failed! {}", day, e);
+        }
+    }
+
+    let expected = |expected: &Option<String>, answer: &Answer| match expected {
+        Some(expected) if expected != &answer.get_result() => {
+            format!(" (expected {})", expected)
+        }
+        _ => String::new(),
+    };
+    match &outcome.result_a {
+        Ok(answer) => println!(
+            "Day {}, Part A = {}{}",
+            day,
+            answer.get_result(),
+            expected(&results.expected_a, answer)
+        ),
         Err(error) => println!("Day {}, Part A failed! {}", day, error),
     }
-    match result_b {
-        Ok(answer) => println!("Day {}, Part B = {}", day, answer.get_result()),
+    match &outcome.result_b {
+        Ok(answer) => println!(
+            "Day {}, Part B = {}{}",
+            day,
+            answer.get_result(),
+            expected(&results.expected_b, answer)
+        ),
         Err(error) => println!("Day {}, Part B failed! {}", day, error),
     }
 
     println!(
         "Day {} time: {:.2?} (A: {:.2?}, B: {:.2?})",
-        day, time, time_a, time_b
+        day, outcome.time, outcome.time_a, outcome.time_b
     );
-    time
+    outcome.time
 }
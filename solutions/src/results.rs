@@ -0,0 +1,127 @@
+use std::{fmt::Display, fs, str::FromStr, time::Duration};
+
+use crate::get_inputs_dir;
+
+/// Answers and timing of a day, cached next to its input
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DayResults {
+    pub expected_a: Option<String>,
+    pub expected_b: Option<String>,
+    pub last_a: Option<String>,
+    pub last_b: Option<String>,
+    pub last_time: Option<Duration>,
+}
+
+impl DayResults {
+    /// Loads the recorded results, or empty results when nothing was recorded yet
+    pub fn load(year: u16, day: u8) -> Result<Self, String> {
+        let path = get_inputs_dir()?
+            .join(year.to_string())
+            .join(format!("results_{}.txt", day));
+        if !path.is_file() {
+            return Ok(Self::default());
+        }
+        fs::read_to_string(path).map_err(|e| e.to_string())?.parse()
+    }
+
+    pub fn save(&self, year: u16, day: u8) -> Result<(), String> {
+        let dir = get_inputs_dir()?.join(year.to_string());
+        fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+        fs::write(dir.join(format!("results_{}.txt", day)), self.to_string())
+            .map_err(|e| e.to_string())
+    }
+
+    pub fn has_expected(&self) -> bool {
+        self.expected_a.is_some() || self.expected_b.is_some()
+    }
+
+    /// Whether the last run matched the expected answers; `None` if there is nothing to compare
+    pub fn passes(&self) -> Option<bool> {
+        if !self.has_expected() || self.last_time.is_none() {
+            return None;
+        }
+        let part_passes = |expected: &Option<String>, last: &Option<String>| {
+            expected.is_none() || expected == last
+        };
+        Some(
+            part_passes(&self.expected_a, &self.last_a)
+                && part_passes(&self.expected_b, &self.last_b),
+        )
+    }
+}
+
+impl FromStr for DayResults {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut results = Self::default();
+        for line in s.lines().filter(|l| !l.trim().is_empty()) {
+            let (key, value) = line
+                .split_once(": ")
+                .ok_or(format!("Invalid results line: {}", line))?;
+            let value = value.to_string();
+            match key {
+                "expected_a" => results.expected_a = Some(value),
+                "expected_b" => results.expected_b = Some(value),
+                "last_a" => results.last_a = Some(value),
+                "last_b" => results.last_b = Some(value),
+                "last_time_us" => {
+                    let micros = value.parse::<u64>().map_err(|e| e.to_string())?;
+                    results.last_time = Some(Duration::from_micros(micros));
+                }
+                _ => return Err(format!("Unknown results key: {}", key)),
+            }
+        }
+        Ok(results)
+    }
+}
+
+impl Display for DayResults {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let entries = [
+            ("expected_a", self.expected_a.clone()),
+            ("expected_b", self.expected_b.clone()),
+            ("last_a", self.last_a.clone()),
+            ("last_b", self.last_b.clone()),
+            (
+                "last_time_us",
+                self.last_time.map(|t| t.as_micros().to_string()),
+            ),
+        ];
+        for (key, value) in entries {
+            if let Some(value) = value {
+                writeln!(f, "{}: {}", key, value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DayResults;
+    use std::time::Duration;
+
+    #[test]
+    fn round_trip() {
+        let results = DayResults {
+            expected_a: Some(String::from("142")),
+            expected_b: None,
+            last_a: Some(String::from("142")),
+            last_b: Some(String::from("Merry Christmas")),
+            last_time: Some(Duration::from_micros(1234)),
+        };
+        assert_eq!(results.to_string().parse(), Ok(results));
+    }
+
+    #[test]
+    fn passes() {
+        let mut results = DayResults::default();
+        assert_eq!(results.passes(), None);
+        results.expected_a = Some(String::from("1"));
+        results.last_time = Some(Duration::ZERO);
+        assert_eq!(results.passes(), Some(false));
+        results.last_a = Some(String::from("1"));
+        assert_eq!(results.passes(), Some(true));
+    }
+}
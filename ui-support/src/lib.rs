@@ -6,27 +6,51 @@ pub use display_data::DisplayData;
 pub use display_request::DisplayRequest;
 pub use display_result::DisplayResult;
 
+use common::Grid;
 use egui::epaint::Pos2;
 
-pub fn render_grid<T, CB>(grid: &Vec<Vec<T>>, f: CB) -> Vec<DisplayData>
+/// Anything laid out as rows of cells, which can be drawn with `render_grid`
+pub trait GridCells<T> {
+    /// All cells with their `(x, y)` position
+    fn grid_cells(&self) -> Box<dyn Iterator<Item = ((usize, usize), &T)> + '_>;
+}
+
+impl<T> GridCells<T> for [Vec<T>] {
+    fn grid_cells(&self) -> Box<dyn Iterator<Item = ((usize, usize), &T)> + '_> {
+        Box::new(
+            self.iter()
+                .enumerate()
+                .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, cell)| ((x, y), cell))),
+        )
+    }
+}
+
+impl<T> GridCells<T> for Vec<Vec<T>> {
+    fn grid_cells(&self) -> Box<dyn Iterator<Item = ((usize, usize), &T)> + '_> {
+        self.as_slice().grid_cells()
+    }
+}
+
+impl<T> GridCells<T> for Grid<T> {
+    fn grid_cells(&self) -> Box<dyn Iterator<Item = ((usize, usize), &T)> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+pub fn render_grid<T, G, CB>(grid: &G, f: CB) -> Vec<DisplayData>
 where
+    G: GridCells<T> + ?Sized,
     CB: Fn(&T, Pos2) -> Vec<Option<DisplayData>>,
 {
-    grid.iter()
-        .enumerate()
-        .flat_map(|(y, row)| {
-            row.into_iter()
-                .enumerate()
-                .flat_map(|(x, cell)| {
-                    let pos = Pos2::new(x as f32 + 0.5, y as f32 + 0.5);
-                    f(cell, pos)
-                })
-                .filter_map(|s| s)
-                .collect::<Vec<DisplayData>>()
+    grid.grid_cells()
+        .flat_map(|((x, y), cell)| {
+            let pos = Pos2::new(x as f32 + 0.5, y as f32 + 0.5);
+            f(cell, pos)
         })
-        .map(|s| s.into())
+        .flatten()
         .collect::<Vec<DisplayData>>()
 }
+
 pub fn pos_into_coord(pos: Pos2) -> (usize, usize) {
     ((pos.x - 0.5) as usize, (pos.y - 0.5) as usize)
 }
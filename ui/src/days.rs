@@ -2,7 +2,7 @@ use eframe::egui::{self, Ui};
 use lazy_async_promise::ImmediateValuePromise;
 use std::{error::Error, fmt::Display, sync::Arc, time::Duration};
 
-use aoc2023::days::Solution;
+use aoc2023::days::{ParameterValue, PuzzleInfo, Registration, Solution};
 use common::Answer;
 use futures::lock::Mutex;
 
@@ -10,6 +10,7 @@ mod visualization_data;
 use visualization_data::VisualizationData;
 
 pub trait PuzzleViewportUi {
+    fn get_year(&self) -> u16;
     fn get_day(&self) -> u8;
     fn update(&mut self, ctx: &egui::Context);
 }
@@ -27,7 +28,9 @@ impl Display for PuzzleError {
 impl Error for PuzzleError {}
 
 pub struct PuzzleViewport {
+    pub year: u16,
     pub day: u8,
+    info: &'static PuzzleInfo,
     puzzle: Arc<Mutex<Box<dyn Solution + Send>>>,
     update_callback_ctx: Option<egui::Context>,
     part_a: Option<PuzzleAnswerPromise>,
@@ -35,15 +38,18 @@ pub struct PuzzleViewport {
     puzzle_visualizer: PuzzleVisualizer,
 }
 impl PuzzleViewport {
-    pub fn new(day: u8, puzzle: Box<dyn Solution + Send>) -> Self {
-        let puzzle = Arc::new(Mutex::new(puzzle));
+    pub fn new(registration: &'static Registration) -> Self {
+        let info = &registration.info;
+        let puzzle = Arc::new(Mutex::new(registration.create()));
         Self {
-            day,
+            year: info.year,
+            day: info.day,
+            info,
             puzzle: puzzle.clone(),
             update_callback_ctx: None,
             part_a: None,
             part_b: None,
-            puzzle_visualizer: PuzzleVisualizer::new(day, puzzle),
+            puzzle_visualizer: PuzzleVisualizer::new(info, puzzle),
         }
     }
 
@@ -55,11 +61,14 @@ impl PuzzleViewport {
     }
 
     fn solve_part(&mut self, second_part: bool) -> PuzzleAnswerPromise {
+        let year = self.year;
         let day = self.day;
         let update_callback = self.update_callback();
         let puzzle = Arc::clone(&self.puzzle);
         let updater = async move {
-            let input = aoc2023::get_input(day, None).await.map_err(PuzzleError)?;
+            let input = aoc2023::get_input(year, day, None)
+                .await
+                .map_err(PuzzleError)?;
             let mut solution = puzzle.lock().await;
             let start = std::time::Instant::now();
             let answer = if second_part {
@@ -76,6 +85,29 @@ impl PuzzleViewport {
     }
 }
 
+impl PuzzleViewport {
+    fn update_parameters(&mut self, ui: &mut Ui) {
+        if self.info.parameters.is_empty() {
+            return;
+        }
+        let Some(mut solution) = self.puzzle.try_lock() else {
+            ui.label("Parameters are locked while solving");
+            return;
+        };
+        egui::Grid::new("puzzle_parameters").show(ui, |ui| {
+            for (field, parameter) in solution.parameters().into_iter().zip(self.info.parameters) {
+                ui.label(field.name).on_hover_text(parameter.description);
+                match field.value {
+                    ParameterValue::Unsigned(value) => ui.add(egui::DragValue::new(value)),
+                    ParameterValue::Signed(value) => ui.add(egui::DragValue::new(value)),
+                    ParameterValue::Float(value) => ui.add(egui::DragValue::new(value)),
+                };
+                ui.end_row();
+            }
+        });
+    }
+}
+
 fn display_result(ui: &mut Ui, result: &mut Option<PuzzleAnswerPromise>) {
     if let Some(state) = result {
         match state.poll_state() {
@@ -101,13 +133,23 @@ fn display_result(ui: &mut Ui, result: &mut Option<PuzzleAnswerPromise>) {
 }
 
 impl PuzzleViewportUi for PuzzleViewport {
+    fn get_year(&self) -> u16 {
+        self.year
+    }
     fn get_day(&self) -> u8 {
         self.day
     }
     fn update(&mut self, ctx: &egui::Context) {
         self.update_callback_ctx = Some(ctx.clone());
         egui::CentralPanel::default().show(ctx, |ui| {
-            ui.heading(format!("Day {:02}", self.day));
+            ui.heading(format!(
+                "{} Day {:02}: {}",
+                self.year, self.day, self.info.title
+            ));
+            for assumption in self.info.assumptions {
+                ui.label(format!("Assumes: {}", assumption));
+            }
+            self.update_parameters(ui);
             ui.horizontal(|ui| {
                 if ui.button("Solve A").clicked() {
                     self.part_a = Some(self.solve_part(false));
@@ -127,7 +169,9 @@ impl PuzzleViewportUi for PuzzleViewport {
 }
 
 struct PuzzleVisualizer {
+    year: u16,
     day: u8,
+    has_visualization: bool,
     puzzle: Arc<Mutex<Box<dyn Solution + Send>>>,
     update_callback_ctx: Option<egui::Context>,
 
@@ -137,9 +181,11 @@ struct PuzzleVisualizer {
 }
 
 impl PuzzleVisualizer {
-    pub fn new(day: u8, puzzle: Arc<Mutex<Box<dyn Solution + Send>>>) -> Self {
+    pub fn new(info: &PuzzleInfo, puzzle: Arc<Mutex<Box<dyn Solution + Send>>>) -> Self {
         Self {
-            day,
+            year: info.year,
+            day: info.day,
+            has_visualization: info.has_visualization,
             puzzle,
             update_callback_ctx: None,
 
@@ -160,7 +206,8 @@ impl PuzzleVisualizer {
         self.update_callback_ctx = Some(ctx.clone());
         ui.horizontal(|ui| {
             ui.set_height(18.0);
-            if ui.button("Try visualize").clicked() {
+            let button = ui.add_enabled(self.has_visualization, egui::Button::new("Visualize"));
+            if button.clicked() {
                 self.visualization_zoom = None;
                 self.visualization_index = 0;
                 self.shape_data = Some(self.fetch_shapes());
@@ -233,7 +280,7 @@ impl PuzzleVisualizer {
                 );
                 if ui.button("-").clicked() {
                     if self.visualization_index > 0 {
-                        self.visualization_index = self.visualization_index - 1;
+                        self.visualization_index -= 1;
                     } else {
                         self.visualization_index = result_count - 1;
                     }
@@ -267,6 +314,7 @@ impl PuzzleVisualizer {
     }
 
     fn fetch_shapes(&mut self) -> PuzzleShapesPromise {
+        let year = self.year;
         let day = self.day;
         let update_callback = self.update_callback();
         let puzzle = Arc::clone(&self.puzzle);
@@ -274,7 +322,9 @@ impl PuzzleVisualizer {
             result_index: self.visualization_index,
         };
         let updater = async move {
-            let input = aoc2023::get_input(day, None).await.map_err(PuzzleError)?;
+            let input = aoc2023::get_input(year, day, None)
+                .await
+                .map_err(PuzzleError)?;
             let mut solution = puzzle.lock().await;
             let shapes = solution.get_shapes(input, request).unwrap_or_default();
             let data = VisualizationData::from(shapes);
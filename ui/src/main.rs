@@ -11,7 +11,7 @@ async fn main() -> Result<(), eframe::Error> {
         ..Default::default()
     };
     eframe::run_native(
-        "Advent of Code 2023 - UI interface",
+        "Advent of Code - UI interface",
         options,
         Box::new(|cc| {
             // This gives us image support:
@@ -30,27 +30,36 @@ struct MyApp {
 impl eframe::App for MyApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         egui::CentralPanel::default().show(ctx, |ui| {
-            ui.heading("AOC 2023");
-            ui.horizontal_wrapped(|ui| {
-                for day in 1..=25 {
-                    let caption = format!("Day {:02}", day);
-                    if ui.button(caption).clicked() {
-                        let solution = aoc2023::days::get_day(day).unwrap();
-                        self.viewport_puzzle = Some(Box::new(PuzzleViewport::new(day, solution)));
+            for year in aoc2023::days::years() {
+                let max_day = aoc2023::get_max_day(year);
+                ui.heading(format!("AOC {}", year));
+                ui.horizontal_wrapped(|ui| {
+                    for registration in aoc2023::days::registry_for_year(year) {
+                        let caption = format!("Day {:02}", registration.info.day);
+                        let button = ui
+                            .add_enabled(
+                                registration.info.day <= max_day,
+                                egui::Button::new(caption),
+                            )
+                            .on_hover_text(registration.info.title);
+                        if button.clicked() {
+                            self.viewport_puzzle =
+                                Some(Box::new(PuzzleViewport::new(registration)));
+                        }
                     }
-                }
-            })
+                });
+            }
         });
 
-        if self.viewport_puzzle.is_some() {
-            let viewport_puzzle = self.viewport_puzzle.as_ref().unwrap();
+        if let Some((year, day)) = self
+            .viewport_puzzle
+            .as_ref()
+            .map(|p| (p.get_year(), p.get_day()))
+        {
             ctx.show_viewport_immediate(
                 egui::ViewportId::from_hash_of("puzzle_viewport"),
                 egui::ViewportBuilder::default()
-                    .with_title(format!(
-                        "AOC 2023 Puzzle Day {:02}",
-                        viewport_puzzle.get_day()
-                    ))
+                    .with_title(format!("AOC {} Puzzle Day {:02}", year, day))
                     .with_resizable(true)
                     .with_inner_size([800.0, 600.0]),
                 move |ctx, class| {